@@ -0,0 +1,26 @@
+#!/usr/bin/env bash
+# times terwijl.lox with this tree and with an older commit, which is built from a copy in a
+# temporary directory that is removed again: bench/run.sh <commit>
+set -euo pipefail
+cd "$(dirname "$0")/.."
+if [ $# -ne 1 ]; then
+    echo "usage: bench/run.sh <commit>" >&2
+    exit 64
+fi
+base=$(git rev-parse --verify --quiet "$1^{commit}") || {
+    echo "bench/run.sh: '$1' is not a commit" >&2
+    exit 64
+}
+work=$(mktemp -d)
+trap 'rm -rf "$work"' EXIT
+
+cargo build --release --quiet
+mkdir "$work/tree"
+git archive "$base" | tar -x -C "$work/tree"
+cargo build --release --quiet --manifest-path "$work/tree/Cargo.toml" --target-dir "$work/target"
+
+TIMEFORMAT='%3R s'
+echo "$1:"
+time "$work/target/release/lox" bench/terwijl.lox
+echo "now:"
+time target/release/lox bench/terwijl.lox
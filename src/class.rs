@@ -0,0 +1,86 @@
+use std::{
+    cell::{Cell, RefCell},
+    collections::{HashMap, HashSet},
+    rc::Rc,
+};
+
+use crate::{function::Function, value::Value};
+
+// what is declared with 'eigenschap Dier { ... }', the methods a klas promises to have
+#[derive(Debug)]
+pub struct TraitType {
+    pub name: String,
+    pub required: Vec<String>,
+    // the methods that already have a body, a klas can replace them with its own
+    pub methods: HashMap<String, Rc<Function>>,
+}
+impl TraitType {
+    pub fn new(
+        name: String,
+        required: Vec<String>,
+        methods: HashMap<String, Rc<Function>>,
+    ) -> Self {
+        Self {
+            name,
+            required,
+            methods,
+        }
+    }
+}
+
+// what is declared with 'klas Hond { ... }', calling it creates an object
+#[derive(Debug)]
+pub struct ClassType {
+    pub name: String,
+    pub methods: HashMap<String, Rc<Function>>,
+    pub traits: Vec<Rc<TraitType>>,
+    // the fields that are declared with 'vast', they can only be set in init
+    pub consts: HashSet<String>,
+}
+impl ClassType {
+    pub fn new(
+        name: String,
+        methods: HashMap<String, Rc<Function>>,
+        traits: Vec<Rc<TraitType>>,
+        consts: HashSet<String>,
+    ) -> Self {
+        Self {
+            name,
+            methods,
+            traits,
+            consts,
+        }
+    }
+
+    // the methods of the klas itself come first, then the ones of its eigenschappen
+    pub fn find_method(&self, name: &str) -> Option<Rc<Function>> {
+        (self.methods.get(name))
+            .or_else(|| self.traits.iter().find_map(|t| t.methods.get(name)))
+            .cloned()
+    }
+
+    pub fn implements(&self, trait_type: &Rc<TraitType>) -> bool {
+        self.traits.iter().any(|t| Rc::ptr_eq(t, trait_type))
+    }
+}
+
+#[derive(Debug)]
+pub struct Instance {
+    pub class: Rc<ClassType>,
+    pub fields: RefCell<HashMap<String, Value>>,
+    // while init runs, the 'vast' fields can still be set
+    pub initializing: Cell<bool>,
+}
+impl Instance {
+    pub fn new(class: Rc<ClassType>) -> Self {
+        Self {
+            class,
+            fields: RefCell::new(HashMap::new()),
+            initializing: Cell::new(false),
+        }
+    }
+
+    pub fn get(&self, name: &str) -> Option<Value> {
+        self.fields.borrow().get(name).cloned()
+    }
+}
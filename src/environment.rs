@@ -1,66 +1,119 @@
-use crate::{token::Token, value::Value};
-use std::collections::HashMap;
+use crate::{
+    error::{ErrorKind, RoxError},
+    messages::Code,
+    suggest,
+    symbol::Symbol,
+    token::Token,
+    value::Value,
+};
+use std::{cell::RefCell, rc::Rc};
 
+// Every scope from the globals to the innermost one, side by side, so the scope the resolver
+// points at is one index away. The scopes are shared through an Rc so that functions can hold
+// on to the scopes they were declared in, even after the interpreter has left them.
 #[derive(Debug, Clone)]
 pub struct Env {
-    child: Option<Box<Env>>,
-    vars: HashMap<String, Value>,
+    scopes: Vec<Rc<RefCell<Scope>>>,
 }
+
+// the values are kept in the order they were declared, which is the slot the resolver gave them,
+// the names are only needed to declare something again and for error messages
+#[derive(Debug, Default)]
+struct Scope {
+    names: Vec<Symbol>,
+    values: Vec<Value>,
+}
+impl Scope {
+    // declaring a name again in the same scope reuses its slot, like the resolver does
+    fn insert(&mut self, name: Symbol, value: Value) {
+        match self.names.iter().position(|&other| other == name) {
+            Some(slot) => self.values[slot] = value,
+            None => {
+                self.names.push(name);
+                self.values.push(value);
+            }
+        }
+    }
+}
+
 impl Env {
     pub fn new() -> Self {
         Self {
-            child: None,
-            vars: HashMap::new(),
+            scopes: vec![Rc::default()],
         }
     }
 
     pub fn create_new_child(&mut self) {
-        match &mut self.child {
-            Some(child) => child.create_new_child(),
-            None => self.child = Some(Box::new(Env::new())),
+        self.scopes.push(Rc::default());
+    }
+
+    pub fn kill_youngest_child(&mut self) {
+        self.scopes.pop();
+    }
+
+    // the scopes of a closure with a new one for a call, made at once so the vec is only made once
+    pub fn with_child(&self) -> Self {
+        let mut scopes = Vec::with_capacity(self.scopes.len() + 1);
+        scopes.extend(self.scopes.iter().cloned());
+        scopes.push(Rc::default());
+        Self { scopes }
+    }
+
+    pub fn insert_value(&mut self, name: Symbol, value: Value) {
+        if let Some(scope) = self.scopes.last() {
+            scope.borrow_mut().insert(name, value);
         }
     }
 
-    pub fn kill_youngest_child(&mut self) -> bool {
-        match &mut self.child {
-            Some(child) => {
-                if !child.kill_youngest_child() {
-                    self.child = None; 
-                }
+    // a local the resolver found, the resolver also checked it isn't 'vast'
+    pub fn get_at(&self, depth: usize, slot: usize) -> Value {
+        self.scope(depth).borrow().values[slot].clone()
+    }
+
+    pub fn assign_at(&mut self, depth: usize, slot: usize, value: Value) {
+        self.scope(depth).borrow_mut().values[slot] = value;
+    }
+
+    fn scope(&self, depth: usize) -> &Rc<RefCell<Scope>> {
+        let index = (self.scopes.len().checked_sub(depth + 1))
+            .expect("the resolver counted more scopes than there are");
+        &self.scopes[index]
+    }
+
+    // a global can be used by a proces before it is declared, then its slot isn't there yet
+    pub fn get_global(&self, slot: usize) -> Option<Value> {
+        self.scopes[0].borrow().values.get(slot).cloned()
+    }
+
+    pub fn assign_global(&mut self, slot: usize, value: Value) -> bool {
+        match self.scopes[0].borrow_mut().values.get_mut(slot) {
+            Some(old) => {
+                *old = value;
                 true
             }
             None => false,
         }
     }
 
-    pub fn insert_value(&mut self, name: &String, value: Value) {
-        if let Some(ref mut child) = self.child {
-            child.insert_value(&name, value);
-            return;
-        }
-        self.vars.insert(name.clone(), value);
+    // by name, for the exports of a module
+    pub fn global_value(&self, name: Symbol) -> Option<Value> {
+        let scope = self.scopes[0].borrow();
+        let slot = scope.names.iter().position(|&other| other == name)?;
+        Some(scope.values[slot].clone())
     }
 
-    pub fn get_value(&self, token: &Token) -> Option<Value> {
-        if let Some(child) = &self.child {
-            if let Some(value) = child.get_value(token) {
-                return Some(value);
-            }
-        }
-        self.vars.get(&token.lexeme).cloned()
+    // the error for a variable that doesn't exist, with the names that look like it
+    pub fn unknown(&self, name: &Token) -> RoxError {
+        let msg = Code::UnknownVariable.with(&[&name.lexeme]);
+        let names = self.names();
+        let close = suggest::closest(&name.lexeme, names.iter().map(|name| &**name));
+        RoxError::run(ErrorKind::Name, &name.span, msg).with_help(suggest::did_you_mean(&close))
     }
 
-    pub fn replace_value(&mut self, name: &Token, new_value: &Value) -> Result<(), String> {
-        if let Some(ref mut child) = self.child {
-            if let Ok(()) = child.replace_value(name, &new_value) {
-                return Ok(());
-            }
-        }
-        if let Some(old_value) = self.vars.get_mut(&name.lexeme) {
-            *old_value = new_value.clone();
-            Ok(())
-        } else {
-            Err(format!("'{}' is een onbekende variabele.", name.lexeme))
-        }
+    // every name that can be reached from here, the inner scopes first
+    pub fn names(&self) -> Vec<Rc<str>> {
+        let scopes = self.scopes.iter().rev();
+        let names = scopes.flat_map(|scope| scope.borrow().names.clone());
+        names.map(Symbol::name).collect()
     }
 }
@@ -0,0 +1,404 @@
+use std::{
+    env,
+    fmt::Display,
+    path::{Path, PathBuf},
+    rc::Rc,
+};
+
+use colored::{Color, Colorize};
+
+use crate::messages::{Code, Message, Text};
+
+// what went wrong, so an embedder can decide what to do without reading the message
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    // the code can't be scanned or parsed
+    Syntax,
+    // a variable, field, key or module that doesn't exist
+    Name,
+    // a value of the wrong type
+    Type,
+    // the wrong number or names of arguments
+    Arguments,
+    // changing something that is 'vast'
+    Const,
+    // an index outside of a list, or a key that isn't in a map
+    Index,
+    Import,
+    Recursion,
+    // a file that can't be read
+    Io,
+    // everything else, like the errors of the built-in functions
+    Runtime,
+    // code that runs, but is probably not what was meant
+    Lint,
+}
+
+// a warning is shown and the code still runs, unless it is denied
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+// the step of running a file in which the error was found
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    Scan,
+    Parse,
+    Resolve,
+    Run,
+}
+
+// the code of one file, kept around so errors can show the line they are about
+#[derive(Debug)]
+pub struct Source {
+    pub path: Option<PathBuf>,
+    pub text: String,
+}
+impl Source {
+    pub fn new(path: Option<PathBuf>, text: String) -> Self {
+        Self { path, text }
+    }
+
+    pub fn name(&self) -> String {
+        match &self.path {
+            Some(path) => path.display().to_string(),
+            None => Text::Input.to_string(),
+        }
+    }
+
+    fn line(&self, line: usize) -> Option<&str> {
+        self.text.lines().nth(line.checked_sub(1)?)
+    }
+}
+
+// where something is in the source, a column of 0 means only the line is known
+// u32 like rustc uses, every token has one so it is kept small
+#[derive(Debug, Clone, Default)]
+pub struct Span {
+    pub line: u32,
+    pub column: u32,
+    pub offset: u32,
+    pub length: u32,
+    pub source: Option<Rc<Source>>,
+}
+impl Span {
+    pub fn new(
+        line: usize,
+        column: usize,
+        offset: usize,
+        length: usize,
+        source: Rc<Source>,
+    ) -> Self {
+        Self {
+            line: line as u32,
+            column: column as u32,
+            offset: offset as u32,
+            length: length as u32,
+            source: Some(source),
+        }
+    }
+
+    // the place right after this span
+    pub fn after(&self) -> Span {
+        Span {
+            column: self.column + self.length,
+            offset: self.offset + self.length,
+            length: 1,
+            ..self.clone()
+        }
+    }
+}
+impl From<usize> for Span {
+    fn from(line: usize) -> Self {
+        Self {
+            line: line as u32,
+            ..Self::default()
+        }
+    }
+}
+impl From<&Span> for Span {
+    fn from(span: &Span) -> Self {
+        span.clone()
+    }
+}
+
+// a step on the way to a runtime error, like a proces that was called or a round of a loop
+#[derive(Debug, Clone)]
+pub struct TraceFrame {
+    pub construct: String,
+    // where the step started, like the call of the proces
+    pub span: Span,
+}
+impl TraceFrame {
+    pub fn new(construct: String, span: Span) -> Self {
+        Self { construct, span }
+    }
+
+    pub fn file(&self) -> String {
+        self.span
+            .source
+            .as_ref()
+            .map_or(Text::Input.to_string(), |source| source.name())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RoxError {
+    pub kind: ErrorKind,
+    // the same in every language, so it can be looked up
+    pub code: Code,
+    pub message: String,
+    pub span: Span,
+    pub phase: Phase,
+    pub severity: Severity,
+    // other places that help to understand the error, like where a variable was declared
+    pub labels: Vec<(Span, String)>,
+    // the innermost step comes first, because the steps are added while the error goes up
+    pub trace: Vec<TraceFrame>,
+    // a tip for fixing it, like the name that was probably meant
+    pub help: Option<Box<str>>,
+}
+impl RoxError {
+    pub fn new(
+        kind: ErrorKind,
+        phase: Phase,
+        span: impl Into<Span>,
+        message: impl Into<Message>,
+    ) -> Self {
+        let message = message.into();
+        Self {
+            kind,
+            code: message.code,
+            message: message.text,
+            span: span.into(),
+            phase,
+            severity: Severity::Error,
+            labels: Vec::new(),
+            trace: Vec::new(),
+            help: None,
+        }
+    }
+
+    pub fn scan(span: impl Into<Span>, message: impl Into<Message>) -> Self {
+        Self::new(ErrorKind::Syntax, Phase::Scan, span, message)
+    }
+
+    pub fn parse(span: impl Into<Span>, message: impl Into<Message>) -> Self {
+        Self::new(ErrorKind::Syntax, Phase::Parse, span, message)
+    }
+
+    pub fn resolve(kind: ErrorKind, span: impl Into<Span>, message: impl Into<Message>) -> Self {
+        Self::new(kind, Phase::Resolve, span, message)
+    }
+
+    pub fn run(kind: ErrorKind, span: impl Into<Span>, message: impl Into<Message>) -> Self {
+        Self::new(kind, Phase::Run, span, message)
+    }
+
+    pub fn warning(span: impl Into<Span>, message: impl Into<Message>) -> Self {
+        Self {
+            severity: Severity::Warning,
+            ..Self::new(ErrorKind::Lint, Phase::Resolve, span, message)
+        }
+    }
+
+    pub fn with_label(mut self, span: impl Into<Span>, label: impl Into<String>) -> Self {
+        self.labels.push((span.into(), label.into()));
+        self
+    }
+
+    pub fn with_help(mut self, help: Option<String>) -> Self {
+        self.help = help.map(String::into_boxed_str);
+        self
+    }
+
+    pub fn in_frame(mut self, construct: String, span: &Span) -> Self {
+        self.trace.push(TraceFrame::new(construct, span.clone()));
+        self
+    }
+
+    // the codes of sysexits.h, like the Lox of the book
+    pub fn exit_code(&self) -> i32 {
+        match (self.kind, self.phase) {
+            (ErrorKind::Io, _) => 66,
+            (_, Phase::Scan | Phase::Parse | Phase::Resolve) => 65,
+            (_, Phase::Run) => 70,
+        }
+    }
+}
+impl Display for RoxError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "[line {}] {} {}",
+            self.span.line,
+            header(self),
+            self.message
+        )
+    }
+}
+
+// the only place where errors are printed, gives back the exit code for the process
+pub fn report(errors: &[RoxError]) -> i32 {
+    for error in errors {
+        eprint!("{}", render_trace(&error.trace));
+        eprint!("{}", render(error));
+    }
+    if errors.len() > 1 {
+        eprintln!("{}", Text::ErrorCount.with(&[&errors.len()]).red());
+    }
+    if let Some(error) = errors.first() {
+        let hint = Text::ExplainHint.with(&[&program_name(), &error.code.id()]);
+        eprintln!("{}", hint.blue());
+    }
+    errors.first().map_or(0, RoxError::exit_code)
+}
+
+// warnings don't stop the program, so they have no exit code and no hint
+pub fn warn(warnings: &[RoxError]) {
+    for warning in warnings {
+        eprint!("{}", render(warning));
+    }
+    if warnings.len() > 1 {
+        eprintln!("{}", Text::WarningCount.with(&[&warnings.len()]).yellow());
+    }
+}
+
+fn header(error: &RoxError) -> String {
+    match error.severity {
+        Severity::Error => Text::ErrorHeader.with(&[&error.code.id()]),
+        Severity::Warning => Text::WarningHeader.with(&[&error.code.id()]),
+    }
+}
+
+// the name Rox was started with, so the hint can be copied as it is
+fn program_name() -> String {
+    env::args()
+        .next()
+        .and_then(|path| Some(Path::new(&path).file_stem()?.to_string_lossy().to_string()))
+        .unwrap_or("rox".to_string())
+}
+
+// shows the line of the error with the wrong part underlined, like rustc does
+pub fn render(error: &RoxError) -> String {
+    let Some(source) = &error.span.source else {
+        let l = "[line ".blue();
+        let i = format!("] {} ", header(error)).blue();
+        let mut out = format!("{}{}{}{}\n", l, error.span.line, i, error.message.red());
+        if let Some(help) = &error.help {
+            out += &format!("  {} {}\n", Text::Help.template().blue(), help);
+        }
+        return out;
+    };
+
+    // the labels in another file can't be shown next to this one
+    let mut marks = vec![(&error.span, None)];
+    for (span, label) in &error.labels {
+        if span
+            .source
+            .as_ref()
+            .is_some_and(|other| Rc::ptr_eq(other, source))
+        {
+            marks.push((span, Some(label.as_str())));
+        }
+    }
+    marks.sort_by_key(|(span, _)| (span.line, span.column));
+
+    let width = marks
+        .iter()
+        .map(|(span, _)| span.line)
+        .max()
+        .unwrap_or(0)
+        .to_string()
+        .len();
+    let bar = format!("{} |", " ".repeat(width)).blue();
+
+    let color = match error.severity {
+        Severity::Error => Color::Red,
+        Severity::Warning => Color::Yellow,
+    };
+    let header = header(error).color(color).bold();
+    let mut out = format!("{} {}\n", header, error.message.bold());
+    let column = source
+        .line(error.span.line as usize)
+        .map_or(error.span.column as usize, |text| {
+            char_column(text, error.span.column)
+        });
+    out += &format!(
+        "{}{} {}:{}:{}\n",
+        " ".repeat(width),
+        "-->".blue(),
+        source.name(),
+        error.span.line,
+        column.max(1)
+    );
+    out += &format!("{}\n", bar);
+
+    let mut last_line = 0;
+    for (span, label) in marks {
+        let Some(text) = source.line(span.line as usize) else {
+            continue;
+        };
+        if span.line != last_line {
+            let number = format!("{:>width$} |", span.line, width = width).blue();
+            out += &format!("{} {}\n", number, text);
+            last_line = span.line;
+        }
+        if span.column == 0 {
+            continue;
+        }
+
+        // the underline has to line up with the characters, not the bytes
+        let start = span.column as usize - 1;
+        let end = (start + span.length as usize).min(text.len());
+        let before = char_column(text, span.column) - 1;
+        let under = text.get(start..end).map_or(1, |s| s.chars().count()).max(1);
+        let marker = match label {
+            None => "^".repeat(under).color(color).bold(),
+            Some(label) => format!("{} {}", "-".repeat(under), label).blue(),
+        };
+        out += &format!("{} {}{}\n", bar, " ".repeat(before), marker);
+    }
+    if let Some(help) = &error.help {
+        let hulp = Text::Help.template().blue();
+        out += &format!("{} {} {}\n", " ".repeat(width), hulp, help);
+    }
+    out
+}
+
+// the column of a span counts bytes, but the characters are what people see
+fn char_column(text: &str, column: u32) -> usize {
+    let start = (column as usize).saturating_sub(1);
+    text.get(..start).map_or(start, |s| s.chars().count()) + 1
+}
+
+// the outermost step first, like Python does, and a recursion is shown only once
+pub fn render_trace(trace: &[TraceFrame]) -> String {
+    if trace.is_empty() {
+        return String::new();
+    }
+    let mut out = format!("{}\n", Text::TraceHeader.template().blue());
+    let mut frames = trace.iter().rev().peekable();
+    while let Some(frame) = frames.next() {
+        out += &format!(
+            "  {} ({}:{})\n",
+            frame.construct,
+            frame.file(),
+            frame.span.line
+        );
+        let mut repeated = 0;
+        while frames.next_if(|next| same_frame(next, frame)).is_some() {
+            repeated += 1;
+        }
+        if repeated > 0 {
+            out += &format!("  {}\n", Text::Repeated.with(&[&repeated]).blue());
+        }
+    }
+    out
+}
+
+fn same_frame(a: &TraceFrame, b: &TraceFrame) -> bool {
+    a.construct == b.construct && a.span.line == b.span.line && a.span.offset == b.span.offset
+}
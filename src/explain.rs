@@ -0,0 +1,846 @@
+use colored::Colorize;
+
+use crate::messages::{locale, Code, Locale, Text};
+
+// the long story behind a code, for 'explain R0003'
+pub struct Explanation {
+    pub dutch: &'static str,
+    pub english: &'static str,
+    // a program that gives the error, and the same program fixed
+    pub wrong: &'static [&'static str],
+    pub right: &'static [&'static str],
+}
+
+macro_rules! explanations {
+    ($($code: ident => [$dutch: expr, $english: expr, [$($wrong: expr),* $(,)?], [$($right: expr),* $(,)?] $(,)?],)*) => {
+        pub fn explanation(code: Code) -> Explanation {
+            match code {
+                $(Code::$code => Explanation {
+                    dutch: $dutch,
+                    english: $english,
+                    wrong: &[$($wrong),*],
+                    right: &[$($right),*],
+                },)*
+            }
+        }
+    };
+}
+
+explanations! {
+    UnexpectedCharacter => [
+        "Rox kent dit teken niet. Alleen letters, cijfers, '_' en de tekens van Rox zelf (zoals + - * / ^ = < > ! ( ) { } [ ] , . ; : ?) mogen buiten een tekst staan.",
+        "Rox doesn't know this character. Only letters, digits, '_' and the symbols of Rox itself (like + - * / ^ = < > ! ( ) { } [ ] , . ; : ?) can be used outside of a string.",
+        ["laat prijs = 5 $;"],
+        ["laat prijs = 5;"],
+    ],
+    UnterminatedString => [
+        "Een tekst begint met '\"' en moet ook met '\"' eindigen. Hier gaat het bestand verder tot het einde zonder dat de tekst wordt afgesloten.",
+        "A string starts with '\"' and has to end with '\"' too. Here the file runs to its end without the string being closed.",
+        ["roep \"Hallo wereld;"],
+        ["roep \"Hallo wereld\";"],
+    ],
+    MissingSemicolon => [
+        "Elke opdracht eindigt met een ';'. Rox wijst naar de plek direct na de opdracht, ook als de volgende opdracht pas op de volgende regel begint.",
+        "Every statement ends with a ';'. Rox points at the spot right after the statement, even when the next statement only starts on the next line.",
+        ["laat breedte = 3", "roep breedte;"],
+        ["laat breedte = 3;", "roep breedte;"],
+    ],
+    MissingParen => [
+        "Een lijst met parameters, argumenten of velden die met '(' begint, moet ook met ')' worden afgesloten.",
+        "A list of parameters, arguments or fields that starts with '(' has to be closed with ')' too.",
+        ["proces som(a, b {", "  retour a + b;", "}"],
+        ["proces som(a, b) {", "  retour a + b;", "}"],
+    ],
+    MissingBrace => [
+        "Een blok, klas, eigenschap of map die met '{' begint, moet ook met '}' worden afgesloten. Rox wijst ook aan waar het blok begon.",
+        "A block, class, trait or map that starts with '{' has to be closed with '}' too. Rox also points at where the block started.",
+        ["als wellus {", "  roep 1;"],
+        ["als wellus {", "  roep 1;", "}"],
+    ],
+    MissingBracket => [
+        "Een lijst of een index die met '[' begint, moet ook met ']' worden afgesloten.",
+        "A list or an index that starts with '[' has to be closed with ']' too.",
+        ["laat cijfers = [1, 2, 3;"],
+        ["laat cijfers = [1, 2, 3];"],
+    ],
+    UnclosedGroup => [
+        "Haakjes rond een expressie, zoals (1 + 2), moeten worden afgesloten voordat de opdracht verder gaat.",
+        "Parentheses around an expression, like (1 + 2), have to be closed before the statement goes on.",
+        ["roep (1 + 2 * 3;"],
+        ["roep (1 + 2) * 3;"],
+    ],
+    UnexpectedToken => [
+        "Op deze plek verwachtte Rox een waarde, zoals een getal, een tekst, een naam of een expressie tussen haakjes. Vaak is er iets vergeten, zoals de waarde na '='.",
+        "At this spot Rox expected a value, like a number, a string, a name or an expression in parentheses. Often something was left out, like the value after '='.",
+        ["laat x = ;"],
+        ["laat x = 0;"],
+    ],
+    ExpectedPipe => [
+        "Een losse '|' bestaat niet in Rox. De pijplijn schrijf je als '|>', en 'of' is het woord voor de logische of.",
+        "A single '|' doesn't exist in Rox. The pipeline is written as '|>', and 'of' is the word for logical or.",
+        ["[3, 1, 2] | sorteer() |> toon();"],
+        ["[3, 1, 2] |> sorteer() |> toon();"],
+    ],
+    ImportNotAtTop => [
+        "'importeer' en 'exporteer' gaan over het hele bestand, dus ze kunnen niet binnen een blok of een proces staan.",
+        "'importeer' and 'exporteer' are about the whole file, so they can't be inside a block or a function.",
+        ["proces wortel_van(x) {", "  importeer wiskunde;", "  retour wiskunde.wortel(x);", "}"],
+        ["importeer wiskunde;", "proces wortel_van(x) {", "  retour wiskunde.wortel(x);", "}"],
+    ],
+    ExpectedModulePath => [
+        "Na 'importeer' komt de naam van een ingebouwde module, zoals wiskunde, of het pad naar een bestand tussen aanhalingstekens.",
+        "After 'importeer' comes the name of a built-in module, like wiskunde, or the path to a file in quotes.",
+        ["importeer 42;"],
+        ["importeer wiskunde;"],
+    ],
+    InvalidPath => [
+        "Een module krijgt de naam van het bestand, zonder '.lox'. Dit pad wijst niet naar een bestand, dus er is geen naam voor de module.",
+        "A module gets the name of its file, without '.lox'. This path doesn't point at a file, so there is no name for the module.",
+        ["importeer \"..\";"],
+        ["importeer wiskunde;"],
+    ],
+    ExpectedModuleName => [
+        "Met 'als' geef je een module een andere naam. Na 'als' moet dan die naam komen.",
+        "With 'als' you give a module another name. That name has to come after 'als'.",
+        ["importeer wiskunde als;"],
+        ["importeer wiskunde als w;"],
+    ],
+    InvalidExport => [
+        "Alleen dingen met een naam kunnen worden geëxporteerd: variabelen, constanten, processen, records, klassen en eigenschappen.",
+        "Only things with a name can be exported: variables, constants, functions, records, classes and traits.",
+        ["exporteer roep 1;"],
+        ["exporteer laat een = 1;"],
+    ],
+    ExpectedProcesName => [
+        "Na 'exporteer proces' moet de naam van het proces komen. Een proces zonder naam kan niet worden geëxporteerd.",
+        "After 'exporteer proces' comes the name of the function. A function without a name can't be exported.",
+        ["exporteer proces (x) {", "  retour x;", "}"],
+        ["exporteer proces zelf(x) {", "  retour x;", "}"],
+    ],
+    ExpectedParenAfterProcesName => [
+        "Na de naam van een proces komen de parameters tussen '(' en ')', ook als er geen parameters zijn.",
+        "After the name of a function come its parameters between '(' and ')', even when there are none.",
+        ["exporteer proces hallo {", "  roep \"hallo\";", "}"],
+        ["exporteer proces hallo() {", "  roep \"hallo\";", "}"],
+    ],
+    ExpectedProcesBody => [
+        "De inhoud van een proces staat altijd tussen '{' en '}', ook als het maar één opdracht is.",
+        "The body of a function is always between '{' and '}', even when it is only one statement.",
+        ["proces dubbel(x) retour x * 2;"],
+        ["proces dubbel(x) {", "  retour x * 2;", "}"],
+    ],
+    ExpectedParamName => [
+        "Tussen de haakjes van een proces staan de namen van de parameters, gescheiden door komma's.",
+        "Between the parentheses of a function are the names of the parameters, separated by commas.",
+        ["proces som(a, 2) {", "  retour a + 2;", "}"],
+        ["proces som(a, b) {", "  retour a + b;", "}"],
+    ],
+    DuplicateParam => [
+        "Twee parameters met dezelfde naam kunnen niet, want dan is niet duidelijk welke waarde de naam heeft.",
+        "Two parameters with the same name can't work, because then it is unclear which value the name has.",
+        ["proces som(a, a) {", "  retour a + a;", "}"],
+        ["proces som(a, b) {", "  retour a + b;", "}"],
+    ],
+    DefaultNeeded => [
+        "Argumenten worden op volgorde ingevuld. Als een parameter een standaardwaarde heeft, moeten alle parameters erna er ook een hebben, anders kan een argument nooit worden overgeslagen.",
+        "Arguments are filled in in order. When a parameter has a default value, all parameters after it need one too, or an argument could never be left out.",
+        ["proces groet(groet = \"Hallo\", naam) {", "  retour groet + \", \" + naam;", "}"],
+        ["proces groet(naam, groet = \"Hallo\") {", "  retour groet + \", \" + naam;", "}"],
+    ],
+    ParamAfterRest => [
+        "Een '...' parameter krijgt alle argumenten die over zijn. Daarna kan dus geen parameter meer komen.",
+        "A '...' parameter gets every argument that is left. So no parameter can come after it.",
+        ["proces tel(...rest, laatste) {", "  retour lengte(rest);", "}"],
+        ["proces tel(eerste, ...rest) {", "  retour lengte(rest);", "}"],
+    ],
+    ExpectedParenAfterProces => [
+        "Een proces zonder naam, als waarde, begint met 'proces' en dan meteen de parameters tussen haakjes.",
+        "A function without a name, used as a value, starts with 'proces' followed directly by its parameters in parentheses.",
+        ["laat dubbel = proces x { retour x * 2; };"],
+        ["laat dubbel = proces (x) { retour x * 2; };"],
+    ],
+    ExpectedArrow => [
+        "Een kort proces schrijf je als (x) => x * 2. Na de parameters moet dan '=>' komen.",
+        "A short function is written as (x) => x * 2. After the parameters comes '=>'.",
+        ["laat dubbel = (x) -> x * 2;"],
+        ["laat dubbel = (x) => x * 2;"],
+    ],
+    ExpectedRecordName => [
+        "Na 'record' komt de naam van het record, en daarna de velden tussen haakjes.",
+        "After 'record' comes the name of the record, and then its fields in parentheses.",
+        ["record (x, y);"],
+        ["record Punt(x, y);"],
+    ],
+    ExpectedParenAfterRecordName => [
+        "De velden van een record staan tussen '(' en ')', direct na de naam.",
+        "The fields of a record are between '(' and ')', right after the name.",
+        ["record Punt x, y;"],
+        ["record Punt(x, y);"],
+    ],
+    ExpectedFieldName => [
+        "Hier moet de naam van een veld staan, zoals in een record, een vast veld van een klas of een patroon als {pi, wortel}.",
+        "The name of a field has to be here, like in a record, a constant field of a class or a pattern like {pi, wortel}.",
+        ["record Punt(x, 2);"],
+        ["record Punt(x, y);"],
+    ],
+    DuplicateField => [
+        "Elk veld van een record moet een eigen naam hebben.",
+        "Every field of a record needs a name of its own.",
+        ["record Punt(x, x);"],
+        ["record Punt(x, y);"],
+    ],
+    ExpectedClassName => [
+        "Na 'klas' komt de naam van de klas.",
+        "After 'klas' comes the name of the class.",
+        ["klas {", "  init() {}", "}"],
+        ["klas Hond {", "  init() {}", "}"],
+    ],
+    ExpectedTraitName => [
+        "Na 'eigenschap' komt de naam van de eigenschap, en na 'implementeert' de namen van de eigenschappen die de klas heeft.",
+        "After 'eigenschap' comes the name of the trait, and after 'implementeert' the names of the traits the class has.",
+        ["eigenschap {", "  geluid();", "}"],
+        ["eigenschap Dier {", "  geluid();", "}"],
+    ],
+    ExpectedClassBody => [
+        "De vaste velden en methoden van een klas staan tussen '{' en '}'.",
+        "The constant fields and methods of a class are between '{' and '}'.",
+        ["klas Hond", "  geluid() { retour \"woef\"; }"],
+        ["klas Hond {", "  geluid() { retour \"woef\"; }", "}"],
+    ],
+    ExpectedParenAfterMethodName => [
+        "Na de naam van een methode komen de parameters tussen '(' en ')', ook als er geen zijn.",
+        "After the name of a method come its parameters between '(' and ')', even when there are none.",
+        ["klas Hond {", "  geluid { retour \"woef\"; }", "}"],
+        ["klas Hond {", "  geluid() { retour \"woef\"; }", "}"],
+    ],
+    ExpectedTraitBody => [
+        "De methoden van een eigenschap staan tussen '{' en '}'.",
+        "The methods of a trait are between '{' and '}'.",
+        ["eigenschap Dier geluid();"],
+        ["eigenschap Dier {", "  geluid();", "}"],
+    ],
+    DuplicateMethod => [
+        "Een klas of eigenschap kan geen twee methoden met dezelfde naam hebben. Rox kiest een methode op naam, niet op het aantal argumenten.",
+        "A class or trait can't have two methods with the same name. Rox picks a method by its name, not by the number of arguments.",
+        ["klas Hond {", "  geluid() { retour \"woef\"; }", "  geluid(hard) { retour \"WOEF\"; }", "}"],
+        ["klas Hond {", "  geluid() { retour \"woef\"; }", "  blaf() { retour \"WOEF\"; }", "}"],
+    ],
+    ExpectedMethodName => [
+        "In een eigenschap staan alleen methoden, elk met een naam gevolgd door de parameters.",
+        "A trait only contains methods, each with a name followed by its parameters.",
+        ["eigenschap Dier {", "  laat x = 1;", "}"],
+        ["eigenschap Dier {", "  geluid();", "}"],
+    ],
+    PatternNeedsValue => [
+        "Een patroon zoals [a, b] of {pi, wortel} haalt waarden uit iets anders, dus na het patroon moet '=' met die waarde komen.",
+        "A pattern like [a, b] or {pi, wortel} takes values out of something else, so the pattern has to be followed by '=' and that value.",
+        ["laat [a, b];"],
+        ["laat [a, b] = [1, 2];"],
+    ],
+    ExpectedVariableName => [
+        "Na 'laat' of 'voor' komt de naam van een variabele, of een patroon zoals [a, b].",
+        "After 'laat' or 'voor' comes the name of a variable, or a pattern like [a, b].",
+        ["laat 5 = 3;"],
+        ["laat vijf = 3;"],
+    ],
+    ExpectedConstName => [
+        "Na 'vast' komt de naam van de constante.",
+        "After 'vast' comes the name of the constant.",
+        ["vast = 3.14;"],
+        ["vast PI = 3.14;"],
+    ],
+    ConstNeedsValue => [
+        "Een constante kan later niet meer veranderen, dus ze moet meteen een waarde krijgen.",
+        "A constant can never change later, so it has to get its value right away.",
+        ["vast PI;"],
+        ["vast PI = 3.14;"],
+    ],
+    ExpectedVan => [
+        "Een telling schrijf je als 'voor i van 0 tot 10'. Na de naam komt 'van'.",
+        "A counting loop is written as 'voor i van 0 tot 10'. After the name comes 'van'.",
+        ["voor i = 0 tot 10", "  roep i;"],
+        ["voor i van 0 tot 10", "  roep i;"],
+    ],
+    ExpectedTot => [
+        "Een telling schrijf je als 'voor i van 0 tot 10'. Na het begin komt 'tot' en dan het einde, dat zelf niet meer meedoet.",
+        "A counting loop is written as 'voor i van 0 tot 10'. After the start comes 'tot' and then the end, which is not included.",
+        ["voor i van 0 10", "  roep i;"],
+        ["voor i van 0 tot 10", "  roep i;"],
+    ],
+    ExpectedVanOrIn => [
+        "Er zijn twee soorten 'voor': 'voor i van 0 tot 10' telt, en 'voor x in lijst' loopt door een lijst, tupel, map, tekst of generator.",
+        "There are two kinds of 'voor': 'voor i van 0 tot 10' counts, and 'voor x in lijst' loops through a list, tuple, map, string or generator.",
+        ["voor x uit [1, 2, 3]", "  roep x;"],
+        ["voor x in [1, 2, 3]", "  roep x;"],
+    ],
+    ReturnOutsideProces => [
+        "'retour' geeft een waarde terug aan wie het proces aanriep. Buiten een proces is er niemand om iets aan terug te geven.",
+        "'retour' gives a value back to whoever called the function. Outside of a function there is nobody to give it back to.",
+        ["retour 5;"],
+        ["proces vijf() {", "  retour 5;", "}"],
+    ],
+    YieldOutsideProces => [
+        "'lever' maakt van een proces een generator. Buiten een proces is er geen generator om een waarde aan te leveren.",
+        "'lever' turns a function into a generator. Outside of a function there is no generator to hand a value to.",
+        ["lever 1;"],
+        ["proces een() {", "  lever 1;", "}"],
+    ],
+    AssignOnlyVariables => [
+        "Bij 'a, b = b, a' staan links alleen namen van variabelen. Velden of indexen kunnen daar niet.",
+        "In 'a, b = b, a' only names of variables can be on the left. Fields or indexes can't.",
+        ["laat a = 1;", "a, 2 = 2, a;"],
+        ["laat a = 1;", "laat b = 2;", "a, b = b, a;"],
+    ],
+    ExpectedEqualsAfterNames => [
+        "Na een rij namen met komma's verwacht Rox een '=' met de nieuwe waarden, zoals in 'a, b = b, a;'.",
+        "After a row of names with commas Rox expects a '=' with the new values, like in 'a, b = b, a;'.",
+        ["laat a = 1;", "laat b = 2;", "a, b;"],
+        ["laat a = 1;", "laat b = 2;", "a, b = b, a;"],
+    ],
+    AssignCountMismatch => [
+        "Bij 'a, b = 1, 2' krijgt elke naam links de waarde die rechts op dezelfde plek staat, dus links en rechts moeten even lang zijn. Eén lijst rechts mag ook.",
+        "In 'a, b = 1, 2' every name on the left gets the value at the same place on the right, so both sides have to be equally long. A single list on the right works too.",
+        ["laat a = 1;", "laat b = 2;", "a, b = 3, 4, 5;"],
+        ["laat a = 1;", "laat b = 2;", "a, b = 3, 4;"],
+    ],
+    InvalidAssignTarget => [
+        "Links van '=' moet iets staan dat een waarde kan krijgen: een variabele, een veld zoals p.x of een plek in een lijst zoals l[0].",
+        "The left side of '=' has to be something that can get a value: a variable, a field like p.x or a place in a list like l[0].",
+        ["laat a = 1;", "a + 1 = 3;"],
+        ["laat a = 1;", "a = 3 - 1;"],
+    ],
+    ExpectedNameAfterDot => [
+        "Na een '.' komt de naam van een veld of methode.",
+        "After a '.' comes the name of a field or method.",
+        ["importeer wiskunde;", "roep wiskunde.;"],
+        ["importeer wiskunde;", "roep wiskunde.pi;"],
+    ],
+    PositionalAfterNamed => [
+        "Argumenten zonder naam worden op volgorde ingevuld. Na een benoemd argument is niet meer duidelijk welke plek dat is, dus dan moeten alle argumenten een naam hebben.",
+        "Arguments without a name are filled in in order. After a named argument that order is no longer clear, so then every argument needs a name.",
+        ["proces deel(a, b) { retour a / b; }", "roep deel(a: 6, 3);"],
+        ["proces deel(a, b) { retour a / b; }", "roep deel(a: 6, b: 3);"],
+    ],
+    ThisOutsideClass => [
+        "'dit' is het object waarop een methode werd aangeroepen. Buiten de methoden van een klas of eigenschap is er geen object.",
+        "'dit' is the object a method was called on. Outside of the methods of a class or trait there is no object.",
+        ["proces naam() {", "  retour dit.naam;", "}"],
+        ["klas Hond {", "  naam() {", "    retour \"Rex\";", "  }", "}"],
+    ],
+    YieldPosition => [
+        "Een generator kan alleen stoppen tussen twee opdrachten. Daarom staat 'lever' alleen los, of als de hele waarde van 'laat' of '=', niet midden in een expressie.",
+        "A generator can only pause between two statements. That is why 'lever' can only be on its own, or be the whole value of 'laat' or '=', not in the middle of an expression.",
+        ["proces teller() {", "  roep 1 + lever 2;", "}"],
+        ["proces teller() {", "  laat x = lever 2;", "  roep 1 + x;", "}"],
+    ],
+    ExpectedColonAfterKey => [
+        "In een map staat tussen elke sleutel en zijn waarde een ':'.",
+        "In a map there is a ':' between every key and its value.",
+        ["laat leeftijden = {\"Jan\" 12};"],
+        ["laat leeftijden = {\"Jan\": 12};"],
+    ],
+    ExpectedInAfterName => [
+        "In een comprehension komt na 'voor' en de naam altijd 'in', zoals [x * x voor x in 0 tot 10].",
+        "In a comprehension 'voor' and the name are always followed by 'in', like [x * x voor x in 0 tot 10].",
+        ["roep [x * x voor x van 0 tot 10];"],
+        ["roep [x * x voor x in 0 tot 10];"],
+    ],
+    UnknownPragma => [
+        "Een commentaar dat met 'rox:' begint is een pragma: een opdracht voor Rox zelf. Er bestaan er twee, 'sta_toe' om waarschuwingen in dit bestand niet te tonen en 'verbied' om er fouten van te maken, met de codes tussen haakjes.",
+        "A comment that starts with 'rox:' is a pragma: an instruction for Rox itself. There are two, 'sta_toe' to not show warnings in this file and 'verbied' to make them errors, with the codes between parentheses.",
+        ["// rox: negeer(R0501)"],
+        ["// rox: sta_toe(R0501)"],
+    ],
+    NotAWarning => [
+        "Alleen waarschuwingen, de codes R05xx, kunnen met een pragma worden toegestaan of verboden. Fouten stoppen het programma altijd.",
+        "Only warnings, the codes R05xx, can be allowed or denied with a pragma. Errors always stop the program.",
+        ["// rox: sta_toe(R0003)"],
+        ["// rox: sta_toe(R0501)"],
+    ],
+
+    ConstAssign => [
+        "Een waarde die met 'vast' is gemaakt kan niet meer veranderen, ook niet door de naam in hetzelfde blok opnieuw te maken. Rox vindt dit al voordat het programma draait, en wijst ook aan waar de constante vast werd gemaakt. Gebruik 'laat' als de waarde moet kunnen veranderen.",
+        "A value made with 'vast' can't change anymore, also not by declaring the name again in the same block. Rox finds this before the program even runs, and also points at where the constant was made. Use 'laat' when the value has to be able to change.",
+        ["vast PI = 3.14;", "PI = 3;"],
+        ["laat pi = 3.14;", "pi = 3;"],
+    ],
+    UnknownVariable => [
+        "Deze naam is nergens gemaakt met 'laat', 'vast', 'proces', 'klas' of een andere declaratie, of hij bestaat alleen in een ander blok. Rox vindt dit al voordat het programma draait. Een variabele moet gemaakt zijn voordat hij wordt gebruikt, alleen een proces mag een globale variabele gebruiken die later komt. Vaak is het een typfout, dan geeft Rox een tip met de naam die er het meest op lijkt.",
+        "This name was never made with 'laat', 'vast', 'proces', 'klas' or another declaration, or it only exists in another block. Rox finds this before the program runs. A variable has to be made before it is used, only a proces can use a global variable that comes later. Often it is a typo, then Rox gives a tip with the name that looks the most like it.",
+        ["laat breedte = 3;", "roep bredte;"],
+        ["laat breedte = 3;", "roep breedte;"],
+    ],
+    ReadInOwnInitializer => [
+        "Een variabele in een blok of proces bestaat pas als zijn beginwaarde klaar is, dus die waarde kan hem niet zelf gebruiken. Ook niet als er buiten het blok een variabele met dezelfde naam is: Rox weet al voor het draaien welke variabele bij een naam hoort. Kies een andere naam. Een proces in de beginwaarde mag zichzelf wel aanroepen.",
+        "A variable in a block or function only exists once its initial value is ready, so that value can't use it. Not even when there is a variable with the same name outside the block: Rox knows which variable a name belongs to before running. Pick another name. A function in the initial value can call itself though.",
+        ["laat x = 1;", "{", "  laat x = x + 1;", "  roep x;", "}"],
+        ["laat x = 1;", "{", "  laat y = x + 1;", "  roep y;", "}"],
+    ],
+    ModuleMissingExport => [
+        "Van een module kan je alleen gebruiken wat met 'exporteer' is gemaakt. Alles anders in het bestand blijft van de module zelf.",
+        "From a module you can only use what was made with 'exporteer'. Everything else in the file stays private to the module.",
+        ["importeer wiskunde;", "roep wiskunde.tau;"],
+        ["importeer wiskunde;", "roep wiskunde.pi * 2;"],
+    ],
+    RecordMissingField => [
+        "Een record heeft precies de velden die bij 'record' tussen haakjes staan.",
+        "A record has exactly the fields that are in parentheses after 'record'.",
+        ["record Punt(x, y);", "roep Punt(1, 2).z;"],
+        ["record Punt(x, y);", "roep Punt(1, 2).y;"],
+    ],
+    NoFieldOrMethod => [
+        "Een object heeft de velden die in zijn methoden met dit.naam = ... een waarde kregen, en de methoden van zijn klas en eigenschappen. Dit veld of deze methode is er niet.",
+        "An object has the fields that got a value in its methods with dit.naam = ..., and the methods of its class and traits. This field or method isn't one of them.",
+        ["klas Hond {", "  init() { dit.naam = \"Rex\"; }", "}", "roep Hond().leeftijd;"],
+        ["klas Hond {", "  init() { dit.naam = \"Rex\"; }", "}", "roep Hond().naam;"],
+    ],
+    NoProperty => [
+        "Alleen modules, records, objecten en mappen hebben iets dat je met een '.' kan opvragen. Een getal, tekst of lijst heeft dat niet, daarvoor zijn de ingebouwde processen zoals lengte.",
+        "Only modules, records, objects and maps have something you can ask for with a '.'. A number, string or list doesn't, that is what the built-in functions like lengte are for.",
+        ["laat cijfers = [1, 2, 3];", "roep cijfers.lengte;"],
+        ["laat cijfers = [1, 2, 3];", "roep lengte(cijfers);"],
+    ],
+    KeyMissing => [
+        "Deze sleutel staat niet in de map. Met 'probeer' kan je de fout opvangen als de sleutel er niet altijd is.",
+        "This key isn't in the map. With 'probeer' you can catch the error when the key isn't always there.",
+        ["laat leeftijden = {\"Jan\": 12};", "roep leeftijden[\"Piet\"];"],
+        ["laat leeftijden = {\"Jan\": 12};", "roep probeer(() => leeftijden[\"Piet\"]);"],
+    ],
+    UnknownParameter => [
+        "Een benoemd argument moet de naam van een van de parameters van het proces hebben.",
+        "A named argument has to have the name of one of the parameters of the function.",
+        ["proces groet(naam) { retour \"Hallo \" + naam; }", "roep groet(wie: \"Jan\");"],
+        ["proces groet(naam) { retour \"Hallo \" + naam; }", "roep groet(naam: \"Jan\");"],
+    ],
+    DuplicateArgument => [
+        "Elke parameter kan maar één waarde krijgen. Hier kreeg hij er een op volgorde én een met zijn naam, of twee keer met zijn naam.",
+        "Every parameter can only get one value. Here it got one in order and one by its name, or its name was used twice.",
+        ["proces deel(a, b) { retour a / b; }", "roep deel(6, a: 3);"],
+        ["proces deel(a, b) { retour a / b; }", "roep deel(6, b: 3);"],
+    ],
+    MissingArgument => [
+        "Een parameter zonder standaardwaarde moet altijd een waarde krijgen, op volgorde of met zijn naam.",
+        "A parameter without a default value always has to get a value, in order or by its name.",
+        ["proces deel(a, b) { retour a / b; }", "roep deel(b: 3);"],
+        ["proces deel(a, b) { retour a / b; }", "roep deel(a: 6, b: 3);"],
+    ],
+    ArityMismatch => [
+        "Het proces kreeg meer of minder argumenten dan het parameters heeft. Parameters met een standaardwaarde mogen worden weggelaten, en een '...' parameter neemt alles wat over is.",
+        "The function got more or fewer arguments than it has parameters. Parameters with a default value can be left out, and a '...' parameter takes everything that is left.",
+        ["proces som(a, b) { retour a + b; }", "roep som(1, 2, 3);"],
+        ["proces som(a, b, c = 0) { retour a + b + c; }", "roep som(1, 2, 3);"],
+    ],
+    NativeNamedArgs => [
+        "De ingebouwde processen hebben geen namen voor hun parameters, dus alle argumenten gaan op volgorde.",
+        "The built-in functions have no names for their parameters, so every argument goes in order.",
+        ["roep lengte(lijst: [1, 2]);"],
+        ["roep lengte([1, 2]);"],
+    ],
+    RecursionTooDeep => [
+        "Een proces dat zichzelf steeds weer aanroept zonder ooit te stoppen, of heel diep, loopt tegen de grens aan. Een 'retour f(x);' aan het eind telt niet mee, want die staart-aanroep laat de stapel niet groeien. recursie_limiet(n) zet de grens hoger, maar niet verder dan de stapel van Rox aankan.",
+        "A function that keeps calling itself without ever stopping, or very deeply, runs into the limit. A 'retour f(x);' at the end doesn't count, because that tail call doesn't grow the stack. recursie_limiet(n) raises the limit, but not further than the stack of Rox can take.",
+        ["proces tel(n) {", "  als n == 0 retour 0;", "  retour 1 + tel(n - 1);", "}", "roep tel(5000);"],
+        ["proces tel(n, totaal = 0) {", "  als n == 0 retour totaal;", "  retour tel(n - 1, totaal + 1);", "}", "roep tel(5000);"],
+    ],
+
+    PatternExpectsList => [
+        "Een patroon met '[' of '(' haalt de waarden uit een lijst of tupel. Deze waarde is geen van beide.",
+        "A pattern with '[' or '(' takes the values out of a list or tuple. This value is neither.",
+        ["laat [a, b] = 5;"],
+        ["laat [a, b] = [5, 6];"],
+    ],
+    PatternLength => [
+        "Elke naam in het patroon krijgt één waarde uit de lijst, dus de lijst moet precies zo lang zijn als het patroon.",
+        "Every name in the pattern gets one value out of the list, so the list has to be exactly as long as the pattern.",
+        ["laat [a, b] = [1, 2, 3];"],
+        ["laat [a, b, c] = [1, 2, 3];"],
+    ],
+    MultiAssignNotList => [
+        "Bij 'a, b = waarde' met één waarde rechts wordt die waarde uit elkaar gehaald, dus het moet een lijst of tupel zijn.",
+        "In 'a, b = value' with one value on the right that value is taken apart, so it has to be a list or tuple.",
+        ["laat a = 1;", "laat b = 2;", "a, b = 3;"],
+        ["laat a = 1;", "laat b = 2;", "a, b = [3, 4];"],
+    ],
+    MultiAssignLength => [
+        "Bij 'a, b = lijst' krijgt elke naam één waarde uit de lijst, dus de lijst moet evenveel waarden hebben als er namen staan.",
+        "In 'a, b = list' every name gets one value out of the list, so the list needs as many values as there are names.",
+        ["laat a = 1;", "laat b = 2;", "a, b = [3, 4, 5];"],
+        ["laat a = 1;", "laat b = 2;", "a, b = [3, 4];"],
+    ],
+    NotATrait => [
+        "Na 'implementeert' staan de namen van eigenschappen. Deze naam hoort bij iets anders, zoals een klas of een variabele.",
+        "After 'implementeert' come the names of traits. This name belongs to something else, like a class or a variable.",
+        ["klas Dier {}", "klas Hond implementeert Dier {}"],
+        ["eigenschap Dier {}", "klas Hond implementeert Dier {}"],
+    ],
+    MissingTraitMethod => [
+        "Een eigenschap noemt methoden zonder inhoud die elke klas met die eigenschap zelf moet schrijven. Deze klas mist er een.",
+        "A trait lists methods without a body that every class with that trait has to write itself. This class is missing one.",
+        ["eigenschap Dier {", "  geluid();", "}", "klas Hond implementeert Dier {}"],
+        ["eigenschap Dier {", "  geluid();", "}", "klas Hond implementeert Dier {", "  geluid() { retour \"woef\"; }", "}"],
+    ],
+    NegateNonNumber => [
+        "Een '-' voor een waarde maakt een getal negatief. Dat kan alleen met een getal.",
+        "A '-' in front of a value makes a number negative. That only works with a number.",
+        ["roep -\"5\";"],
+        ["roep -getal(\"5\");"],
+    ],
+    NotNonBool => [
+        "'!' draait wellus en nietus om. Andere waarden, zoals 0 of niks, tellen in Rox niet als waar of onwaar.",
+        "'!' turns wellus and nietus around. Other values, like 0 or niks, don't count as true or false in Rox.",
+        ["laat aantal = 0;", "roep !aantal;"],
+        ["laat aantal = 0;", "roep !(aantal > 0);"],
+    ],
+    ArithmeticNonNumber => [
+        "Rekenen en vergelijken met -, *, /, <, >, <= en >= kan alleen met getallen. Een tekst met een getal erin kan je eerst omzetten met getal().",
+        "Calculating and comparing with -, *, /, <, >, <= and >= only works with numbers. A string with a number in it can be turned into one with getal() first.",
+        ["roep \"6\" * 2;"],
+        ["roep getal(\"6\") * 2;"],
+    ],
+    PlusTypes => [
+        "'+' telt getallen op en plakt teksten aan elkaar, ook een tekst met een getal. Andere waarden, zoals wellus of een lijst, kunnen dat niet.",
+        "'+' adds numbers and joins strings, also a string with a number. Other values, like wellus or a list, can't do either.",
+        ["roep [1, 2] + 3;"],
+        ["roep voeg_toe([1, 2], 3);"],
+    ],
+    CaretTypes => [
+        "'^' verheft een getal tot een macht, zoals 2 ^ 3 = 8. Dat kan alleen met getallen.",
+        "'^' raises a number to a power, like 2 ^ 3 = 8. That only works with numbers.",
+        ["roep \"2\" ^ 3;"],
+        ["roep 2 ^ 3;"],
+    ],
+    IsNeedsType => [
+        "'is' kijkt of een waarde een object van een klas is, een eigenschap heeft of een bepaald record is. Rechts moet dus een klas, eigenschap of record staan.",
+        "'is' checks whether a value is an object of a class, has a trait or is a certain record. So the right side has to be a class, trait or record.",
+        ["record Punt(x, y);", "roep Punt(1, 2) is \"Punt\";"],
+        ["record Punt(x, y);", "roep Punt(1, 2) is Punt;"],
+    ],
+    OperatorObjectLeft => [
+        "Een operator op een object roept een methode van dat object aan, zoals plus of keer. Een getal of tekst heeft die methoden niet, dus het object moet links staan.",
+        "An operator on an object calls a method of that object, like plus or keer. A number or string doesn't have those methods, so the object has to be on the left.",
+        ["klas Geld {", "  init(centen) { dit.centen = centen; }", "  keer(n) { retour Geld(dit.centen * n); }", "}", "roep (2 * Geld(5)).centen;"],
+        ["klas Geld {", "  init(centen) { dit.centen = centen; }", "  keer(n) { retour Geld(dit.centen * n); }", "}", "roep (Geld(5) * 2).centen;"],
+    ],
+    MissingOperatorMethod => [
+        "Een operator op een object werkt alleen als de klas de methode ervoor heeft: plus (+), min (-), keer (*), gedeeld (/), kleiner_dan (<, >, <=, >=) en gelijk (== en !=).",
+        "An operator on an object only works when the class has the method for it: plus (+), min (-), keer (*), gedeeld (/), kleiner_dan (<, >, <=, >=) and gelijk (== and !=).",
+        ["klas Geld {", "  init(centen) { dit.centen = centen; }", "}", "roep Geld(5) + Geld(2);"],
+        ["klas Geld {", "  init(centen) { dit.centen = centen; }", "  plus(ander) { retour Geld(dit.centen + ander.centen); }", "}", "roep (Geld(5) + Geld(2)).centen;"],
+    ],
+    MustReturnBool => [
+        "De methoden kleiner_dan en gelijk worden gebruikt door <, >, <=, >=, == en !=, dus ze moeten wellus of nietus teruggeven.",
+        "The methods kleiner_dan and gelijk are used by <, >, <=, >=, == and !=, so they have to return wellus or nietus.",
+        ["klas Geld {", "  init(centen) { dit.centen = centen; }", "  kleiner_dan(ander) { retour dit.centen - ander.centen; }", "}", "roep Geld(1) < Geld(2);"],
+        ["klas Geld {", "  init(centen) { dit.centen = centen; }", "  kleiner_dan(ander) { retour dit.centen < ander.centen; }", "}", "roep Geld(1) < Geld(2);"],
+    ],
+    MustReturnText => [
+        "als_tekst bepaalt hoe roep, toon en '+' een object laten zien, dus de methode moet een tekst teruggeven.",
+        "als_tekst decides how roep, toon and '+' show an object, so the method has to return a string.",
+        ["klas Geld {", "  init(centen) { dit.centen = centen; }", "  als_tekst() { retour dit.centen; }", "}", "roep Geld(5);"],
+        ["klas Geld {", "  init(centen) { dit.centen = centen; }", "  als_tekst() { retour \"\" + dit.centen; }", "}", "roep Geld(5);"],
+    ],
+    LogicNonBool => [
+        "'en' en 'of' werken alleen met wellus en nietus. Andere waarden, zoals 1 of een tekst, tellen in Rox niet als waar of onwaar.",
+        "'en' and 'of' only work with wellus and nietus. Other values, like 1 or a string, don't count as true or false in Rox.",
+        ["laat aantal = 1;", "roep aantal en wellus;"],
+        ["laat aantal = 1;", "roep aantal > 0 en wellus;"],
+    ],
+    SpreadNonList => [
+        "Met '...' worden de waarden van een lijst, tupel, map of tekst los als argumenten of elementen ingevuld. Eén losse waarde heeft niets om uit te spreiden.",
+        "With '...' the values of a list, tuple, map or string are filled in one by one as arguments or elements. A single value has nothing to spread.",
+        ["proces som(a, b) { retour a + b; }", "roep som(...5);"],
+        ["proces som(a, b) { retour a + b; }", "roep som(...[5, 6]);"],
+    ],
+    NotCallable => [
+        "Alleen processen, klassen, records en de ingebouwde processen kunnen worden aangeroepen met haakjes.",
+        "Only functions, classes, records and the built-in functions can be called with parentheses.",
+        ["laat prijs = 5;", "roep prijs();"],
+        ["laat prijs = () => 5;", "roep prijs();"],
+    ],
+    GeneratorRunning => [
+        "Een generator kan geen waarde van zichzelf vragen terwijl hij nog bezig is met de vorige, want die waarde bestaat nog niet.",
+        "A generator can't ask itself for a value while it is still working on the previous one, because that value doesn't exist yet.",
+        ["proces teller() {", "  lever volgende(t);", "}", "laat t = teller();", "volgende(t);"],
+        ["proces teller() {", "  lever 1;", "}", "laat t = teller();", "roep volgende(t);"],
+    ],
+    SetFieldOnNonObject => [
+        "Alleen een object van een klas heeft velden die kunnen veranderen. Records, tupels en andere waarden kunnen niet worden veranderd.",
+        "Only an object of a class has fields that can change. Records, tuples and other values can't be changed.",
+        ["record Punt(x, y);", "laat p = Punt(1, 2);", "p.x = 5;"],
+        ["record Punt(x, y);", "laat p = Punt(1, 2);", "p = Punt(5, p.y);"],
+    ],
+    ConditionNotBool => [
+        "De 'als' in een comprehension beslist voor elke waarde of die meedoet, dus de voorwaarde moet wellus of nietus zijn. Andere waarden, zoals 0 of niks, tellen in Rox niet als waar of onwaar.",
+        "The 'als' in a comprehension decides for every value whether it is included, so the condition has to be wellus or nietus. Other values, like 0 or niks, don't count as true or false in Rox.",
+        ["roep [x voor x in [0, 1, 2] als x];"],
+        ["roep [x voor x in [0, 1, 2] als x > 0];"],
+    ],
+    RangeNonNumber => [
+        "'voor i van ... tot ...' en 'in ... tot ...' tellen van het ene getal naar het andere, dus begin en einde moeten getallen zijn.",
+        "'voor i van ... tot ...' and 'in ... tot ...' count from one number to another, so the start and end have to be numbers.",
+        ["voor i van \"1\" tot 3", "  roep i;"],
+        ["voor i van 1 tot 3", "  roep i;"],
+    ],
+    NotIndexable => [
+        "Met [ ] haal je iets uit een lijst, tupel, tekst of map. Deze waarde is geen van die.",
+        "With [ ] you get something out of a list, tuple, string or map. This value is none of those.",
+        ["laat getal = 123;", "roep getal[0];"],
+        ["laat getal = \"123\";", "roep getal[0];"],
+    ],
+    TupleImmutable => [
+        "Een tupel kan niet veranderen nadat hij is gemaakt. Maak een nieuwe tupel, of gebruik een lijst als de waarden moeten kunnen veranderen.",
+        "A tuple can't change after it was made. Make a new tuple, or use a list when the values have to be able to change.",
+        ["laat paar = (1, 2);", "paar[0] = 3;"],
+        ["laat paar = [1, 2];", "paar[0] = 3;"],
+    ],
+    NotAList => [
+        "Met l[i] = waarde verander je een plek in een lijst of map. Een tekst of andere waarde kan zo niet worden veranderd.",
+        "With l[i] = value you change a place in a list or map. A string or other value can't be changed that way.",
+        ["laat woord = \"kat\";", "woord[0] = \"r\";"],
+        ["laat woord = \"kat\";", "woord = \"r\" + woord[1] + woord[2];"],
+    ],
+    UnhandledFout => [
+        "'?' geeft een Fout meteen terug aan wie het proces aanriep. Buiten een proces is er niemand om hem aan terug te geven, dus dan stopt het programma.",
+        "'?' returns a Fout right away to whoever called the function. Outside of a function there is nobody to give it to, so then the program stops.",
+        ["laat x = probeer_getal(\"banaan\")?;"],
+        ["laat x = probeer_getal(\"banaan\");", "als is_fout(x) roep \"geen getal\";"],
+    ],
+    QuestionOnNonResult => [
+        "'?' pakt een Ok uit of geeft een Fout terug. Op een waarde die geen Ok of Fout is, heeft het geen zin.",
+        "'?' unwraps an Ok or returns a Fout. On a value that is neither Ok nor Fout it makes no sense.",
+        ["proces dubbel(tekst) {", "  retour Ok(getal(tekst)? * 2);", "}", "roep dubbel(\"4\");"],
+        ["proces dubbel(tekst) {", "  retour Ok(probeer_getal(tekst)? * 2);", "}", "roep dubbel(\"4\");"],
+    ],
+    NotIterable => [
+        "'voor x in' loopt door een lijst, tupel, map, tekst of generator. Om tot een getal te tellen schrijf je 'voor i van 0 tot n'.",
+        "'voor x in' loops through a list, tuple, map, string or generator. To count up to a number you write 'voor i van 0 tot n'.",
+        ["voor i in 3", "  roep i;"],
+        ["voor i van 0 tot 3", "  roep i;"],
+    ],
+    IndexOutOfRange => [
+        "De plekken in een lijst tellen vanaf 0, dus de laatste plek is lengte - 1. Een index moet een heel getal zijn dat niet negatief is.",
+        "The places in a list count from 0, so the last place is lengte - 1. An index has to be a whole number that isn't negative.",
+        ["laat l = [1, 2, 3];", "roep l[3];"],
+        ["laat l = [1, 2, 3];", "roep l[2];"],
+    ],
+    InvalidIndex => [
+        "Een index in een lijst, tupel of tekst moet een getal zijn. Alleen een map heeft sleutels die ook een tekst kunnen zijn.",
+        "An index in a list, tuple or string has to be a number. Only a map has keys that can be strings too.",
+        ["laat l = [1, 2, 3];", "roep l[\"1\"];"],
+        ["laat l = [1, 2, 3];", "roep l[1];"],
+    ],
+
+    ExpectedResult => [
+        "is_ok, is_fout en uitpakken werken op de Ok en Fout die processen zoals probeer_getal teruggeven. Deze waarde is geen van beide.",
+        "is_ok, is_fout and uitpakken work on the Ok and Fout that functions like probeer_getal return. This value is neither.",
+        ["roep is_ok(5);"],
+        ["roep is_ok(Ok(5));"],
+    ],
+    UnwrapFout => [
+        "uitpakken geeft de waarde in een Ok, maar bij een Fout stopt het programma met de boodschap van die Fout. Kijk eerst met is_ok, of gebruik '?' in een proces.",
+        "uitpakken gives the value inside an Ok, but on a Fout the program stops with the message of that Fout. Check with is_ok first, or use '?' inside a function.",
+        ["roep uitpakken(probeer_getal(\"banaan\"));"],
+        ["laat x = probeer_getal(\"banaan\");", "als is_ok(x) roep uitpakken(x);"],
+    ],
+    NoLength => [
+        "lengte werkt op lijsten, tupels, mappen en teksten. Een getal heeft geen lengte, maar de tekst ervan wel.",
+        "lengte works on lists, tuples, maps and strings. A number has no length, but its text does.",
+        ["roep lengte(12345);"],
+        ["roep lengte(\"\" + 12345);"],
+    ],
+    NotANumber => [
+        "getal zet een tekst zoals \"42\" om in een getal. Deze tekst is geen getal. probeer_getal geeft in zo'n geval een Fout terug in plaats van te stoppen.",
+        "getal turns a string like \"42\" into a number. This string isn't a number. probeer_getal returns a Fout in that case instead of stopping.",
+        ["roep getal(\"banaan\");"],
+        ["roep probeer_getal(\"banaan\");"],
+    ],
+    ExpectedPath => [
+        "lees_bestand verwacht het pad naar een bestand als tekst.",
+        "lees_bestand expects the path to a file as a string.",
+        ["roep lees_bestand(5);"],
+        ["roep probeer_lees_bestand(\"5.txt\");"],
+    ],
+    RecursionLimitInvalid => [
+        "recursie_limiet(n) zet het aantal processen dat tegelijk bezig mag zijn. Dat moet een heel getal boven 0 zijn.",
+        "recursie_limiet(n) sets how many functions can be running at once. That has to be a whole number above 0.",
+        ["recursie_limiet(0);"],
+        ["recursie_limiet(5000);"],
+    ],
+    ExpectedGenerator => [
+        "volgende, stuur en klaar werken op een generator: wat een proces met 'lever' erin teruggeeft als je het aanroept.",
+        "volgende, stuur and klaar work on a generator: what a function with 'lever' in it returns when you call it.",
+        ["proces teller() { lever 1; }", "roep volgende(teller);"],
+        ["proces teller() { lever 1; }", "roep volgende(teller());"],
+    ],
+    ExpectedProces => [
+        "taak start een proces als taak, dus het eerste argument moet een proces zijn. De argumenten voor dat proces komen erachter.",
+        "taak starts a function as a task, so the first argument has to be a function. The arguments for that function come after it.",
+        ["proces zeg(x) { roep x; }", "wacht(taak(zeg(1)));"],
+        ["proces zeg(x) { roep x; }", "wacht(taak(zeg, 1));"],
+    ],
+    WaitInsideTask => [
+        "wacht houdt het hoofdprogramma stil tot de taken klaar zijn. Binnen een taak geef je de andere taken een beurt met 'lever', zoals 'lever slaap(100);'.",
+        "wacht holds the main program until the tasks are done. Inside a task you give the other tasks a turn with 'lever', like 'lever slaap(100);'.",
+        ["proces traag() {", "  wacht(slaap(10));", "}", "wacht(taak(traag));"],
+        ["proces traag() {", "  lever slaap(10);", "}", "wacht(taak(traag));"],
+    ],
+    WaitInvalid => [
+        "wacht kan wachten op een taak, slaap(ms), ontvang(kanaal) of een lijst daarvan. Op andere waarden valt niets te wachten.",
+        "wacht can wait for a task, slaap(ms), ontvang(kanaal) or a list of those. There is nothing to wait for on other values.",
+        ["wacht(100);"],
+        ["wacht(slaap(100));"],
+    ],
+    SleepInvalid => [
+        "slaap verwacht een aantal milliseconden van 0 of meer.",
+        "slaap expects a number of milliseconds of 0 or more.",
+        ["wacht(slaap(-5));"],
+        ["wacht(slaap(5));"],
+    ],
+    ExpectedChannel => [
+        "verstuur en ontvang werken op een kanaal dat met kanaal() is gemaakt.",
+        "verstuur and ontvang work on a channel made with kanaal().",
+        ["verstuur(\"berichten\", 1);"],
+        ["laat berichten = kanaal();", "verstuur(berichten, 1);"],
+    ],
+    ExpectedNumber => [
+        "Dit ingebouwde proces rekent met getallen, dus het argument moet een getal zijn.",
+        "This built-in function calculates with numbers, so the argument has to be a number.",
+        ["importeer wiskunde;", "roep wiskunde.wortel(\"16\");"],
+        ["importeer wiskunde;", "roep wiskunde.wortel(16);"],
+    ],
+    NegativeRoot => [
+        "De wortel van een negatief getal bestaat niet tussen de gewone getallen.",
+        "The square root of a negative number doesn't exist among the ordinary numbers.",
+        ["importeer wiskunde;", "roep wiskunde.wortel(-16);"],
+        ["importeer wiskunde;", "roep wiskunde.wortel(wiskunde.abs(-16));"],
+    ],
+    ExpectedList => [
+        "Dit ingebouwde proces werkt op een lijst (of tupel). Deze waarde is geen lijst.",
+        "This built-in function works on a list (or tuple). This value isn't a list.",
+        ["roep som(12);"],
+        ["roep som([1, 2]);"],
+    ],
+    CallbackMustReturnBool => [
+        "Het proces dat je aan filter geeft, beslist per waarde of die blijft. Daarom moet het wellus of nietus teruggeven.",
+        "The function you give to filter decides for every value whether it stays. That is why it has to return wellus or nietus.",
+        ["roep filter([1, 2, 3], (x) => x - 1);"],
+        ["roep filter([1, 2, 3], (x) => x > 1);"],
+    ],
+    FoldNeedsStart => [
+        "vouw begint met de eerste waarde van de lijst als er geen beginwaarde is. Bij een lege lijst is er geen eerste waarde, dus dan is een beginwaarde nodig.",
+        "vouw starts with the first value of the list when there is no starting value. An empty list has no first value, so then a starting value is needed.",
+        ["roep vouw([], (a, b) => a + b);"],
+        ["roep vouw([], (a, b) => a + b, 0);"],
+    ],
+    SortCallbackInvalid => [
+        "Het proces dat je aan sorteer geeft, vergelijkt twee waarden a en b. Het geeft wellus als a voor b komt en anders nietus, of een getal dat kleiner dan 0 is als a voor b komt.",
+        "The function you give to sorteer compares two values a and b. It returns wellus when a comes before b and nietus otherwise, or a number below 0 when a comes before b.",
+        ["roep sorteer([3, 1, 2], (a, b) => \"a\");"],
+        ["roep sorteer([3, 1, 2], (a, b) => a < b);"],
+    ],
+    CannotCompare => [
+        "sorteer, min en max vergelijken getallen met getallen en teksten met teksten. Een mengsel kan niet zonder een eigen proces dat zegt hoe.",
+        "sorteer, min and max compare numbers with numbers and strings with strings. A mix can't be compared without a function of your own that says how.",
+        ["roep sorteer([3, \"1\", 2]);"],
+        ["roep sorteer([3, 1, 2]);"],
+    ],
+    EmptyList => [
+        "Een lege lijst heeft geen kleinste of grootste waarde.",
+        "An empty list has no smallest or largest value.",
+        ["roep min([]);"],
+        ["roep probeer(() => min([]));"],
+    ],
+    Deadlock => [
+        "Alle taken, en het hoofdprogramma, wachten op iets dat alleen een andere taak kan doen. Vaak wacht een ontvang op een kanaal waar niemand meer iets naar verstuurt.",
+        "All tasks, and the main program, are waiting for something only another task can do. Often an ontvang waits on a channel nobody sends anything to anymore.",
+        ["laat berichten = kanaal();", "roep wacht(ontvang(berichten));"],
+        ["laat berichten = kanaal();", "verstuur(berichten, \"hallo\");", "roep wacht(ontvang(berichten));"],
+    ],
+
+    FileNotFound => [
+        "Het bestand dat Rox moest draaien bestaat niet. Een pad is relatief aan de map van waaruit Rox wordt gestart, en zonder pad zoekt Rox naar file.lox.",
+        "The file Rox had to run doesn't exist. A path is relative to the directory Rox is started from, and without a path Rox looks for file.lox.",
+        ["// lox bestaat_niet.lox"],
+        ["// lox voorbeelden/hallo.lox"],
+    ],
+    ModuleNotFound => [
+        "Het pad van een 'importeer' is relatief aan het bestand dat importeert, niet aan de map van waaruit Rox wordt gestart.",
+        "The path of an 'importeer' is relative to the file that imports, not to the directory Rox is started from.",
+        ["importeer \"vormen.lx\";"],
+        ["importeer wiskunde;"],
+    ],
+    ImportCycle => [
+        "Deze bestanden importeren elkaar in een kring, dus geen van allen kan als eerste worden uitgevoerd. Haal wat ze allebei nodig hebben naar een derde bestand.",
+        "These files import each other in a circle, so none of them can be run first. Move what they both need into a third file.",
+        ["// a.lox", "importeer \"b.lox\";", "// b.lox", "importeer \"a.lox\";"],
+        ["// a.lox", "importeer \"gedeeld.lox\";", "// b.lox", "importeer \"gedeeld.lox\";"],
+    ],
+    FileUnreadable => [
+        "Het bestand bestaat niet, is een map, of Rox mag het niet lezen. Met probeer_lees_bestand krijg je een Fout terug in plaats van dat het programma stopt.",
+        "The file doesn't exist, is a directory, or Rox isn't allowed to read it. With probeer_lees_bestand you get a Fout back instead of the program stopping.",
+        ["roep lees_bestand(\"bestaat_niet.txt\");"],
+        ["roep probeer_lees_bestand(\"bestaat_niet.txt\");"],
+    ],
+
+    UnusedVariable => [
+        "Deze variabele krijgt een waarde, maar die wordt nergens gelezen. Vaak is er een typfout in een andere naam, of is de variabele overgebleven van eerdere code. Het is een waarschuwing: het programma draait gewoon. Begin de naam met '_' als hij bewust niet wordt gebruikt.",
+        "This variable gets a value, but it is never read. Often there is a typo in another name, or the variable was left over from earlier code. It is a warning: the program still runs. Start the name with '_' when it is not used on purpose.",
+        ["laat prijs = 5;", "laat btw = 1.21;", "roep prijs;"],
+        ["laat prijs = 5;", "laat btw = 1.21;", "roep prijs * btw;"],
+    ],
+    ShadowedLoopVariable => [
+        "Een 'laat' in een lus van 'voor' maakt een nieuwe variabele met dezelfde naam als de lusvariabele. Vanaf daar is de lusvariabele niet meer te zien, en de nieuwe verandert de lus niet. Kies een andere naam.",
+        "A 'laat' in a 'voor' loop makes a new variable with the same name as the loop variable. From there on the loop variable can't be seen anymore, and the new one doesn't change the loop. Pick another name.",
+        ["voor i van 0 tot 3 {", "  laat i = 10;", "  roep i;", "}"],
+        ["voor i van 0 tot 3 {", "  laat dubbel = i * 2;", "  roep dubbel;", "}"],
+    ],
+    UnreachableAfterReturn => [
+        "Na 'retour' gaat het proces meteen terug, dus de opdrachten die erna in hetzelfde blok staan worden nooit uitgevoerd. Haal ze weg of zet ze voor 'retour'.",
+        "After 'retour' the function goes back right away, so the statements after it in the same block are never run. Remove them or move them before 'retour'.",
+        ["proces dubbel(x) {", "  retour x * 2;", "  roep \"klaar\";", "}", "roep dubbel(2);"],
+        ["proces dubbel(x) {", "  roep \"klaar\";", "  retour x * 2;", "}", "roep dubbel(2);"],
+    ],
+    AlwaysFalseComparison => [
+        "Waarden van een verschillende soort zijn nooit gelijk, een getal is bijvoorbeeld nooit gelijk aan een tekst. Deze '==' is dus altijd nietus. Gebruik getal() of toon() om eerst dezelfde soort te maken.",
+        "Values of a different kind are never equal, a number is never equal to a string for example. So this '==' is always nietus. Use getal() or toon() to make them the same kind first.",
+        ["roep 1 == \"1\";"],
+        ["roep 1 == getal(\"1\");"],
+    ],
+    EmptyLoopBody => [
+        "De inhoud van deze lus is leeg, dus de lus doet niks behalve rondes tellen. Vaak is de inhoud vergeten.",
+        "The body of this loop is empty, so the loop does nothing but count rounds. Often the body was forgotten.",
+        ["voor i van 0 tot 3 {}"],
+        ["voor i van 0 tot 3 {", "  roep i;", "}"],
+    ],
+}
+
+// 'explain R0003' shows one code, just 'explain' lists all of them
+pub fn explain(id: Option<&str>) -> i32 {
+    let Some(id) = id else {
+        for code in Code::ALL {
+            println!("{}  {}", code.id().bold(), code.title());
+        }
+        return 0;
+    };
+    let Some(code) = Code::from_id(id) else {
+        eprintln!("{}", Text::UnknownCode.with(&[&id]));
+        return 64;
+    };
+
+    let explanation = explanation(code);
+    let text = match locale() {
+        Locale::Playful | Locale::Neutral => explanation.dutch,
+        Locale::English => explanation.english,
+    };
+    println!("{} {}\n", code.id().red().bold(), code.title().bold());
+    println!("{}\n", text);
+    println!("{}", Text::Wrong.template().red());
+    for line in explanation.wrong {
+        println!("    {}", line);
+    }
+    println!("\n{}", Text::Right.template().green());
+    for line in explanation.right {
+        println!("    {}", line);
+    }
+    0
+}
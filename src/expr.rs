@@ -1,4 +1,57 @@
-use crate::token::{Literal, Token};
+use std::{cell::Cell, fmt::Display, rc::Rc};
+
+use crate::{
+    stmt::{Fun, Pattern},
+    token::{Literal, Token},
+};
+
+// f(1, ...lijst, naam: "Jan")
+#[derive(Clone)]
+pub enum Arg {
+    Positional(Expr),
+    Spread(Token, Expr),
+    Named(Token, Expr),
+}
+impl Arg {
+    pub fn expr(&self) -> &Expr {
+        match self {
+            Arg::Positional(expr) | Arg::Spread(_, expr) | Arg::Named(_, expr) => expr,
+        }
+    }
+}
+
+// where a variable lives, filled in by the resolver so it doesn't have to be searched for by name
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Place {
+    // only until the resolver has been there, which it always is before anything runs
+    Unresolved,
+    // a slot in the globals, it is still empty when a proces uses a global before it is declared
+    Global(usize),
+    // this many scopes up from the current one, at this slot
+    Local { depth: usize, slot: usize },
+}
+
+// a name that is read or changed, like x in 'roep x;' or 'x = 3;'
+#[derive(Clone)]
+pub struct Variable {
+    pub name: Token,
+    pub place: Cell<Place>,
+}
+impl Variable {
+    pub fn new(name: Token) -> Self {
+        Variable {
+            name,
+            place: Cell::new(Place::Unresolved),
+        }
+    }
+}
+
+// the 'voor' and 'als' parts of a comprehension like [x * x voor x in 0 tot 10 als x > 3]
+#[derive(Clone)]
+pub enum Clause {
+    For(Pattern, Expr, Option<Expr>),
+    If(Token, Expr),
+}
 
 #[derive(Clone)]
 pub enum Expr {
@@ -7,13 +60,28 @@ pub enum Expr {
     Grouping(Box<Expr>),
     Unary(Token, Box<Expr>),
     Binary(Box<Expr>, Token, Box<Expr>),
-    Var(Token),
-    Assign(Token, Box<Expr>),
+    Var(Variable),
+    Assign(Variable, Box<Expr>),
+    Call(Box<Expr>, Token, Vec<Arg>),
+    Pipe(Box<Expr>, Token, Box<Expr>),
+    Try(Box<Expr>, Token),
+    Get(Box<Expr>, Token),
+    Set(Box<Expr>, Token, Box<Expr>),
+    This(Variable),
+    Lambda(Rc<Fun>),
+    List(Vec<Expr>),
+    Tuple(Vec<Expr>),
+    Map(Vec<(Expr, Expr)>),
+    Comprehension(Box<Expr>, Vec<Clause>),
+    MapComprehension(Box<Expr>, Box<Expr>, Vec<Clause>),
+    Index(Box<Expr>, Token, Box<Expr>),
+    SetIndex(Box<Expr>, Token, Box<Expr>, Box<Expr>),
+    Yield(Token, Box<Expr>),
 }
 // used for debugging purposes
-impl Expr {
-    pub fn to_string(&self) -> String {
-        match self {
+impl Display for Expr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
             Expr::Lit(lit) => lit.to_string(),
             Expr::Grouping(expr) => {
                 let expr = *expr.clone();
@@ -23,25 +91,58 @@ impl Expr {
                 let expr = *expr.clone();
                 parenthesize(token.lexeme.clone(), vec![expr])
             }
-            Expr::Binary(left, token, right) | Expr::Logic(left, token, right) => {
+            Expr::Binary(left, token, right)
+            | Expr::Logic(left, token, right)
+            | Expr::Pipe(left, token, right) => {
                 let left = *left.clone();
                 let right = *right.clone();
                 parenthesize(token.lexeme.clone(), vec![left, right])
             }
+            Expr::Call(callee, _, args) => {
+                let mut exprs = vec![*callee.clone()];
+                exprs.extend(args.iter().map(|arg| arg.expr().clone()));
+                parenthesize("call".to_owned(), exprs)
+            }
+            Expr::Try(expr, token) | Expr::Yield(token, expr) => {
+                let expr = *expr.clone();
+                parenthesize(token.lexeme.clone(), vec![expr])
+            }
+            Expr::Get(expr, name) => format!("{}.{}", expr, name.lexeme),
+            Expr::Set(expr, name, value) => format!("{}.{} = {}", expr, name.lexeme, value),
+            Expr::List(exprs) => parenthesize("list".to_owned(), exprs.clone()),
+            Expr::Tuple(exprs) => parenthesize("tuple".to_owned(), exprs.clone()),
+            Expr::Map(pairs) => {
+                let pairs: Vec<Expr> = (pairs.iter())
+                    .map(|(key, value)| Expr::Tuple(vec![key.clone(), value.clone()]))
+                    .collect();
+                parenthesize("map".to_owned(), pairs)
+            }
+            Expr::Comprehension(expr, _) => parenthesize("voor".to_owned(), vec![*expr.clone()]),
+            Expr::MapComprehension(key, value, _) => {
+                parenthesize("voor".to_owned(), vec![*key.clone(), *value.clone()])
+            }
+            Expr::Index(expr, _, index) => format!("{}[{}]", expr, index),
+            Expr::SetIndex(expr, _, index, value) => format!("{}[{}] = {}", expr, index, value),
+            Expr::Lambda(fun) => {
+                let params: Vec<String> =
+                    fun.params.iter().map(|p| p.name.lexeme.clone()).collect();
+                format!("(proces ({}))", params.join(" "))
+            }
+            Expr::Var(variable) | Expr::This(variable) => variable.name.lexeme.clone(),
             Expr::Assign(_, _) => panic!("Unreachable."),
-            Expr::Var(_) => panic!("Unreachable."),
-        }
+        };
+        write!(f, "{}", str)
     }
 }
 
 fn parenthesize(name: String, exprs: Vec<Expr>) -> String {
     let mut out = format!("({}", name.clone());
 
-    for i in 0..exprs.len() {
-        out.push_str(" ");
-        out.push_str(&exprs[i].to_string());
+    for expr in exprs {
+        out.push(' ');
+        out.push_str(&expr.to_string());
     }
 
-    out.push_str(")");
+    out.push(')');
     out
 }
@@ -0,0 +1,61 @@
+use std::{fmt::Debug, rc::Rc};
+
+use crate::{
+    environment::Env, interpreter::Interpreter, messages::Message, stmt::Fun, value::Value,
+};
+
+#[derive(Clone)]
+pub struct Function {
+    pub declaration: Rc<Fun>,
+    pub closure: Env,
+}
+impl Function {
+    pub fn new(declaration: Rc<Fun>, closure: Env) -> Self {
+        Self {
+            declaration,
+            closure,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.declaration.name.lexeme
+    }
+}
+// the closure is left out on purpose, it usually contains the function itself
+impl Debug for Function {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Function({})", self.name())
+    }
+}
+
+// natives get the interpreter so they can call the functions they are given
+pub type NativeFun = fn(&mut Interpreter, &[Value]) -> Result<Value, Message>;
+
+// a function that is written in Rust instead of in Rox
+#[derive(Debug, Clone, Copy)]
+pub struct NativeFn {
+    pub name: &'static str,
+    pub min_arity: usize,
+    pub max_arity: usize,
+    pub fun: NativeFun,
+}
+impl NativeFn {
+    pub fn new(name: &'static str, arity: usize, fun: NativeFun) -> Self {
+        Self::with_optional(name, arity, arity, fun)
+    }
+
+    // for natives of which the last arguments can be left out
+    pub fn with_optional(
+        name: &'static str,
+        min_arity: usize,
+        max_arity: usize,
+        fun: NativeFun,
+    ) -> Self {
+        Self {
+            name,
+            min_arity,
+            max_arity,
+            fun,
+        }
+    }
+}
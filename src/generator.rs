@@ -0,0 +1,89 @@
+use std::{cell::RefCell, fmt::Debug, rc::Rc, vec};
+
+use crate::{
+    environment::Env,
+    error::Span,
+    expr::{Expr, Variable},
+    stmt::{Pattern, Stmt},
+    token::Token,
+    value::Value,
+};
+
+// a proces with 'lever' in it, every time it is resumed it runs until the next 'lever'
+pub struct Generator {
+    pub name: String,
+    // the statements that still have to run, the innermost one last
+    pub frames: Vec<Frame>,
+    pub env: Env,
+    // the variable that gets the value that is sent in with the next resume
+    pub target: Option<Target>,
+    pub running: bool,
+    // what 'retour' gave back, for a generator that runs as a task
+    pub returned: Value,
+}
+impl Generator {
+    pub fn new(name: String, body: Rc<[Stmt]>, env: Env) -> Self {
+        Self {
+            name,
+            frames: vec![Frame::Block {
+                stmts: body,
+                index: 0,
+                scoped: false,
+            }],
+            env,
+            target: None,
+            running: false,
+            returned: Value::Nil,
+        }
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.frames.is_empty()
+    }
+}
+// the frames are left out on purpose, they are only interesting to the interpreter
+impl Debug for Generator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Generator({})", self.name)
+    }
+}
+
+// 'laat x = lever y;' declares x, 'x = lever y;' assigns to it
+pub enum Target {
+    Declare(Token),
+    Assign(Variable),
+}
+
+// a statement that is halfway done, the statements inside it are shared instead of copied
+pub enum Frame {
+    // a scoped block has its own variables, which are thrown away when it is done
+    Block {
+        stmts: Rc<[Stmt]>,
+        index: usize,
+        scoped: bool,
+    },
+    While {
+        condition: Rc<Expr>,
+        body: Rc<[Stmt]>,
+    },
+    // every round gets its own scope for the loop variable, 'current' is the value of the next round
+    For {
+        name: Token,
+        current: f64,
+        end: f64,
+        step: f64,
+        body: Rc<[Stmt]>,
+    },
+    ForIn {
+        pattern: Rc<Pattern>,
+        source: Iteration,
+        body: Rc<[Stmt]>,
+        span: Span,
+    },
+}
+
+// the values a 'voor ... in' loop goes through, a generator is only resumed when its next value is needed
+pub enum Iteration {
+    Values(vec::IntoIter<Value>),
+    Generator(Rc<RefCell<Generator>>),
+}
@@ -1,77 +1,503 @@
-use core::panic;
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    fs, mem,
+    path::{Path, PathBuf},
+    rc::Rc,
+};
 
 use crate::{
-    crash,
+    class::{ClassType, Instance, TraitType},
     environment::Env,
-    expr::Expr,
-    stmt::{If, Stmt},
-    token::Token,
+    error::{ErrorKind, RoxError, Span, TraceFrame},
+    expr::{Arg, Clause, Expr, Place, Variable},
+    function::Function,
+    generator::{Frame, Generator, Iteration, Target},
+    messages::{Code, Message, Text},
+    module::Module,
+    natives,
+    parser::Parser,
+    record::{Record, RecordType},
+    resolver::Resolver,
+    scanner::Scanner,
+    scheduler::Scheduler,
+    stmt::{Class, Fun, If, Import, Param, Pattern, Stmt, Trait},
+    symbol::Symbol,
+    token::{Literal, Token},
     token_type::TokenType,
-    value::Value,
+    value::{map_get, map_insert, Value},
 };
 
+// used to jump out of the statement or expression that is being evaluated
+pub enum Unwind {
+    Return(Value),
+    // 'retour f(x);' hands the call back to the caller, so the stack doesn't grow
+    TailCall(Box<TailCall>),
+    // a runtime error that can still be caught with 'probeer'
+    Error(RoxError),
+}
+impl Unwind {
+    fn error(kind: ErrorKind, span: &Span, message: impl Into<Message>) -> Self {
+        Unwind::Error(RoxError::run(kind, span, message))
+    }
+
+    // adds a step to the trace of an error on its way up, the rest goes through as it is
+    fn trace(self, construct: impl FnOnce() -> String, span: &Span) -> Self {
+        match self {
+            Unwind::Error(error) => Unwind::Error(error.in_frame(construct(), span)),
+            unwind => unwind,
+        }
+    }
+}
+
+pub struct TailCall {
+    callee: Value,
+    args: Vec<Value>,
+    named: Vec<(Token, Value)>,
+    span: Span,
+}
+
+// deep enough for normal recursion, but low enough to never overflow the Rust stack
+const RECURSION_LIMIT: usize = 1000;
+
+// the interpreter recurses on the Rust stack, so it gets a bigger one than the main thread has
+pub const STACK_SIZE: usize = 256 * 1024 * 1024;
+// a raised recursie_limiet can ask for more than the stack has, so a call also stops when
+// less than this is left, which is plenty for whatever runs between two calls
+const STACK_RESERVE: usize = 32 * 1024 * 1024;
+
 pub struct Interpreter {
     env: Env,
+    call_depth: usize,
+    recursion_limit: usize,
+    // where the stack was when the interpreter was made, to know how much of it is used
+    stack_start: usize,
+    scheduler: Scheduler,
+    // the file that is being run, imports are resolved relative to it
+    file: Option<PathBuf>,
+    // every file is only run once, after that the module is taken from here
+    modules: HashMap<PathBuf, Rc<Module>>,
+    // the files that are being imported right now, used to detect import cycles
+    loading: Vec<PathBuf>,
+    exports: Vec<Token>,
+    // natives only get the message of an error, the whole error waits here until they give it back
+    callback_error: Option<RoxError>,
+    // where the native that is running was called, the processen it calls are called from there
+    native_span: Span,
+    // the trace of the last error that probeer caught, for laatste_spoor()
+    caught_trace: Vec<TraceFrame>,
 }
 impl Interpreter {
-    pub fn new() -> Self {
-        Self { env: Env::new() }
+    pub fn new(file: Option<PathBuf>) -> Self {
+        let file = file.map(|file| file.canonicalize().unwrap_or(file));
+        Self {
+            env: Self::global_env(),
+            call_depth: 0,
+            recursion_limit: RECURSION_LIMIT,
+            stack_start: stack_position(),
+            scheduler: Scheduler::new(),
+            loading: file.iter().cloned().collect(),
+            file,
+            modules: HashMap::new(),
+            exports: Vec::new(),
+            callback_error: None,
+            native_span: Span::default(),
+            caught_trace: Vec::new(),
+        }
+    }
+
+    pub fn recursion_limit(&self) -> usize {
+        self.recursion_limit
+    }
+
+    pub fn set_recursion_limit(&mut self, limit: usize) {
+        self.recursion_limit = limit;
+    }
+
+    pub fn scheduler(&mut self) -> &mut Scheduler {
+        &mut self.scheduler
+    }
+
+    // called by probeer, after that the error that was given back as a message is not passed on
+    pub fn catch_error(&mut self) {
+        self.caught_trace = self
+            .callback_error
+            .take()
+            .map_or(Vec::new(), |error| error.trace);
+    }
+
+    // the outermost step first
+    pub fn caught_trace(&self) -> impl Iterator<Item = &TraceFrame> {
+        self.caught_trace.iter().rev()
+    }
+
+    fn global_env() -> Env {
+        let mut env = Env::new();
+        for native in natives::globals() {
+            env.insert_value(Symbol::intern(native.name), Value::Native(native));
+        }
+        env
     }
 
-    pub fn interpret(&mut self, statements: Vec<Stmt>) {
+    pub fn interpret(&mut self, statements: Vec<Stmt>) -> Result<(), RoxError> {
         for statement in statements {
-            self.evaluate_stmt(&statement);
+            match self.evaluate_stmt(&statement) {
+                Ok(()) => (),
+                Err(Unwind::Error(error)) => return Err(error),
+                Err(Unwind::Return(_) | Unwind::TailCall(_)) => panic!("Unreachable."),
+            }
         }
+        Ok(())
     }
 
-    fn evaluate_stmt(&mut self, stmt: &Stmt) {
+    fn evaluate_stmt(&mut self, stmt: &Stmt) -> Result<(), Unwind> {
         match stmt {
             Stmt::Expr(expr) => {
-                self.evaluate_expr(expr);
+                self.evaluate_expr(expr)?;
             }
 
-            Stmt::Print(expr) => print!("{}", self.evaluate_expr(expr).to_string()),
-            Stmt::Println(expr) => println!("{}", self.evaluate_expr(expr).to_string()),
+            Stmt::Print(expr) => {
+                let value = self.evaluate_expr(expr)?;
+                print!("{}", self.text(&value)?)
+            }
+            Stmt::Println(expr) => {
+                let value = self.evaluate_expr(expr)?;
+                println!("{}", self.text(&value)?)
+            }
 
-            Stmt::Var(token, expr) => {
-                let value = self.evaluate_expr(expr);
-                self.env.insert_value(&token.lexeme, value);
+            // the resolver already made sure a 'vast' is never changed
+            Stmt::Var(token, expr) | Stmt::Const(token, expr) => {
+                let value = self.evaluate_expr(expr)?;
+                self.env.insert_value(token.symbol, value);
             }
 
-            Stmt::Block(statements) => self.evaluate_block_stmt(statements),
+            Stmt::Destructure(pattern, expr) => {
+                let value = self.evaluate_expr(expr)?;
+                self.bind_pattern(pattern, value)?;
+            }
+
+            Stmt::MultiAssign(names, equals, exprs) => {
+                self.evaluate_multi_assign_stmt(names, equals, exprs)?
+            }
 
-            Stmt::If(first_if, else_ifs, other) => self.evaluate_if_stmt(first_if, else_ifs, other),
+            Stmt::Block(statements) => self.evaluate_block_stmt(statements)?,
 
-            Stmt::While(expr, statement) => {
-                while let Value::True = self.evaluate_expr(expr) {
-                    self.evaluate_stmt(statement);
+            Stmt::If(first_if, else_ifs, other) => {
+                self.evaluate_if_stmt(first_if, else_ifs, other)?
+            }
+
+            Stmt::While(keyword, expr, statement) => {
+                let mut round = 0;
+                while let Value::True = self.evaluate_expr(expr)? {
+                    round += 1;
+                    self.evaluate_stmt(statement).map_err(|unwind| {
+                        unwind.trace(|| Text::WhileRound.with(&[&round]), &keyword.span)
+                    })?;
                 }
             }
 
             Stmt::For(name, start, end, statement) => {
-                self.evaluate_for_stmt(name, start, end, statement)
+                self.evaluate_for_stmt(name, start, end, statement)?
+            }
+
+            Stmt::ForIn(pattern, keyword, iterable, statement) => {
+                let iterable = self.evaluate_expr(iterable)?;
+                let mut source = iteration(iterable, &keyword.span)?;
+                let mut round = 0;
+                while let Some(value) = self.next_value(&mut source, &keyword.span)? {
+                    round += 1;
+                    // every round gets its own scope, so closures keep their own value
+                    self.env.create_new_child();
+                    self.bind_pattern(pattern, value)?;
+                    let result = self.evaluate_stmt(statement);
+                    self.env.kill_youngest_child();
+                    result.map_err(|unwind| {
+                        unwind.trace(|| Text::ForInRound.with(&[&round]), &keyword.span)
+                    })?;
+                }
+            }
+
+            Stmt::Fun(declaration) => {
+                let fun = Function::new(declaration.clone(), self.env.clone());
+                self.env
+                    .insert_value(declaration.name.symbol, Value::Func(fun.into()));
+            }
+
+            Stmt::Return(_, Expr::Call(callee, paren, args)) => {
+                let callee = self.evaluate_expr(callee)?;
+                let mut values = Vec::new();
+                let mut named = Vec::new();
+                self.evaluate_args(args, &mut values, &mut named)?;
+                return Err(Unwind::TailCall(Box::new(TailCall {
+                    callee,
+                    args: values,
+                    named,
+                    span: paren.span.clone(),
+                })));
+            }
+            Stmt::Return(_, expr) => return Err(Unwind::Return(self.evaluate_expr(expr)?)),
+
+            Stmt::Record(name, fields) => {
+                let fields = fields.iter().map(|field| field.lexeme.clone()).collect();
+                let kind = RecordType::new(name.lexeme.clone(), fields);
+                self.env
+                    .insert_value(name.symbol, Value::RecordType(Rc::new(kind)));
+            }
+
+            Stmt::Class(class) => self.evaluate_class_stmt(class)?,
+            Stmt::Trait(trait_decl) => self.evaluate_trait_stmt(trait_decl),
+
+            Stmt::Import(import) => {
+                let module = self.import_module(import)?;
+                self.env
+                    .insert_value(import.name.symbol, Value::Module(module));
+            }
+
+            Stmt::Export(names, stmt) => {
+                self.evaluate_stmt(stmt)?;
+                self.exports.extend(names.iter().cloned());
             }
         }
+        Ok(())
     }
 
-    fn evaluate_block_stmt(&mut self, statements: &Vec<Stmt>) {
+    fn bind_pattern(&mut self, pattern: &Pattern, value: Value) -> Result<(), Unwind> {
+        match pattern {
+            Pattern::Name(name) => self.env.insert_value(name.symbol, value),
+            Pattern::List(bracket, patterns) => {
+                let values = match value {
+                    Value::List(list) => list.borrow().clone(),
+                    Value::Tuple(tuple) => tuple.to_vec(),
+                    value => {
+                        let msg = Code::PatternExpectsList.with(&[&value]);
+                        return Err(Unwind::error(ErrorKind::Type, &bracket.span, msg));
+                    }
+                };
+                if values.len() != patterns.len() {
+                    let msg = Code::PatternLength.with(&[&patterns.len(), &values.len()]);
+                    return Err(Unwind::error(ErrorKind::Runtime, &bracket.span, msg));
+                }
+                for (pattern, value) in patterns.iter().zip(values) {
+                    self.bind_pattern(pattern, value)?;
+                }
+            }
+            Pattern::Fields(names) => {
+                for name in names {
+                    let field = get_field(&value, name)?;
+                    self.env.insert_value(name.symbol, field);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn evaluate_multi_assign_stmt(
+        &mut self,
+        names: &[Variable],
+        equals: &Token,
+        exprs: &[Expr],
+    ) -> Result<(), Unwind> {
+        // every value is calculated before anything is assigned, so 'a, b = b, a' swaps
+        let mut values = Vec::new();
+        for expr in exprs {
+            values.push(self.evaluate_expr(expr)?);
+        }
+
+        if values.len() == 1 && names.len() > 1 {
+            values = match &values[0] {
+                Value::List(list) => list.borrow().clone(),
+                Value::Tuple(tuple) => tuple.to_vec(),
+                value => {
+                    let msg = Code::MultiAssignNotList.with(&[&value]);
+                    return Err(Unwind::error(ErrorKind::Type, &equals.span, msg));
+                }
+            };
+        }
+        if values.len() != names.len() {
+            let msg = Code::MultiAssignLength.with(&[&names.len(), &values.len()]);
+            return Err(Unwind::error(ErrorKind::Runtime, &equals.span, msg));
+        }
+
+        for (name, value) in names.iter().zip(values) {
+            self.assign(name, value)?;
+        }
+        Ok(())
+    }
+
+    fn import_module(&mut self, import: &Import) -> Result<Rc<Module>, Unwind> {
+        let span = &import.keyword.span;
+
+        let relative_path = match &import.path.literal {
+            Literal::Str(path) => PathBuf::from(path),
+            _ => {
+                if let Some(module) = natives::module(&import.path.lexeme) {
+                    return Ok(Rc::new(module));
+                }
+                PathBuf::from(format!("{}.lox", import.path.lexeme))
+            }
+        };
+
+        let dir = match self.file.as_ref().and_then(|file| file.parent()) {
+            Some(dir) => dir.to_path_buf(),
+            None => PathBuf::new(),
+        };
+        let path = match dir.join(&relative_path).canonicalize() {
+            Ok(path) => path,
+            Err(_) => {
+                let msg = Code::ModuleNotFound.with(&[&relative_path.display()]);
+                return Err(Unwind::error(ErrorKind::Import, span, msg));
+            }
+        };
+
+        if let Some(module) = self.modules.get(&path) {
+            return Ok(module.clone());
+        }
+
+        if let Some(i) = self.loading.iter().position(|file| *file == path) {
+            let mut cycle: Vec<String> = self.loading[i..]
+                .iter()
+                .map(|file| file_name(file))
+                .collect();
+            cycle.push(file_name(&path));
+            let msg = Code::ImportCycle.with(&[&cycle.join(" -> ")]);
+            return Err(Unwind::error(ErrorKind::Import, span, msg));
+        }
+
+        let source = match fs::read_to_string(&path) {
+            Ok(source) => source,
+            Err(err) => {
+                let msg = Code::FileUnreadable.with(&[&path.display(), &err]);
+                return Err(Unwind::error(ErrorKind::Import, span, msg));
+            }
+        };
+        // only the first error of the module is passed on, the importer can't show a list
+        let tokens = Scanner::new(source, Some(path.clone()))
+            .scan_tokens()
+            .map_err(|errors| Unwind::Error(errors[0].clone()))?;
+        let statements = Parser::new(tokens)
+            .parse_statements()
+            .map_err(|errors| Unwind::Error(errors[0].clone()))?;
+        Resolver::new()
+            .resolve(&statements)
+            .map_err(Unwind::Error)?;
+
+        // the module gets its own globals, so it can't see or change those of the importer
+        let previous_env = mem::replace(&mut self.env, Self::global_env());
+        let previous_file = self.file.replace(path.clone());
+        let previous_exports = mem::take(&mut self.exports);
+        self.loading.push(path.clone());
+
+        let result = self.interpret(statements);
+
+        self.loading.pop();
+        let mut exports = HashMap::new();
+        for name in mem::replace(&mut self.exports, previous_exports) {
+            if let Some(value) = self.env.global_value(name.symbol) {
+                exports.insert(name.lexeme, value);
+            }
+        }
+        self.file = previous_file;
+        self.env = previous_env;
+        result.map_err(|error| {
+            Unwind::Error(error).trace(|| Text::ImportFrame.with(&[&file_name(&path)]), span)
+        })?;
+
+        let name = path.file_stem().unwrap_or_default().to_string_lossy();
+        let module = Rc::new(Module::new(name.to_string(), exports));
+        self.modules.insert(path, module.clone());
+        Ok(module)
+    }
+
+    // checks that the klas has every method its eigenschappen ask for
+    fn evaluate_class_stmt(&mut self, class: &Class) -> Result<(), Unwind> {
+        let mut traits = Vec::new();
+        for variable in &class.traits {
+            match self.lookup(variable)? {
+                Value::TraitType(trait_type) => traits.push(trait_type),
+                value => {
+                    let msg = Code::NotATrait.with(&[&value]);
+                    return Err(Unwind::error(ErrorKind::Type, &variable.name.span, msg));
+                }
+            }
+        }
+
+        let methods = self.methods(&class.methods);
+        for trait_type in &traits {
+            for required in &trait_type.required {
+                if !methods.contains_key(required) {
+                    let msg = Code::MissingTraitMethod.with(&[
+                        &class.name.lexeme,
+                        &trait_type.name,
+                        &required,
+                    ]);
+                    return Err(Unwind::error(ErrorKind::Type, &class.name.span, msg));
+                }
+            }
+        }
+
+        let consts = class.consts.iter().map(|c| c.lexeme.clone()).collect();
+        let class_type = ClassType::new(class.name.lexeme.clone(), methods, traits, consts);
+        self.env
+            .insert_value(class.name.symbol, Value::ClassType(Rc::new(class_type)));
+        Ok(())
+    }
+
+    fn evaluate_trait_stmt(&mut self, trait_decl: &Trait) {
+        let required = trait_decl
+            .required
+            .iter()
+            .map(|r| r.lexeme.clone())
+            .collect();
+        let methods = self.methods(&trait_decl.methods);
+        let trait_type = TraitType::new(trait_decl.name.lexeme.clone(), required, methods);
+        self.env.insert_value(
+            trait_decl.name.symbol,
+            Value::TraitType(Rc::new(trait_type)),
+        );
+    }
+
+    fn methods(&self, declarations: &[Rc<Fun>]) -> HashMap<String, Rc<Function>> {
+        (declarations.iter())
+            .map(|method| {
+                let fun = Function::new(method.clone(), self.env.clone());
+                (method.name.lexeme.clone(), Rc::new(fun))
+            })
+            .collect()
+    }
+
+    // the resolver also leaves out the scope of a block that declares nothing
+    fn evaluate_block_stmt(&mut self, statements: &[Stmt]) -> Result<(), Unwind> {
+        if !statements.iter().any(Stmt::declares) {
+            return self.evaluate_stmts(statements);
+        }
         self.env.create_new_child();
+        let result = self.evaluate_stmts(statements);
+        self.env.kill_youngest_child();
+        result
+    }
+
+    fn evaluate_stmts(&mut self, statements: &[Stmt]) -> Result<(), Unwind> {
         for stmt in statements {
-            self.evaluate_stmt(stmt);
+            self.evaluate_stmt(stmt)?;
         }
-        self.env.kill_youngest_child();
+        Ok(())
     }
 
-    fn evaluate_if_stmt(&mut self, first_if: &If, else_ifs: &Vec<If>, other: &Option<Box<Stmt>>) {
-        if let Value::True = self.evaluate_expr(&first_if.should_execute) {
+    fn evaluate_if_stmt(
+        &mut self,
+        first_if: &If,
+        else_ifs: &[If],
+        other: &Option<Box<Stmt>>,
+    ) -> Result<(), Unwind> {
+        if let Value::True = self.evaluate_expr(&first_if.should_execute)? {
             // execute the first if
-            self.evaluate_stmt(&first_if.statement);
+            self.evaluate_stmt(&first_if.statement)?;
         } else {
             // check for other else_ifs
             let mut else_if_executed = false;
             for else_if in else_ifs {
-                if let Value::True = self.evaluate_expr(&else_if.should_execute) {
-                    self.evaluate_stmt(&else_if.statement);
+                if let Value::True = self.evaluate_expr(&else_if.should_execute)? {
+                    self.evaluate_stmt(&else_if.statement)?;
                     else_if_executed = true;
                     break;
                 }
@@ -80,87 +506,173 @@ impl Interpreter {
             // execute if there is an else and no else_ifs were executed
             if !else_if_executed {
                 if let Some(other) = other {
-                    self.evaluate_stmt(other);
+                    self.evaluate_stmt(other)?;
                 }
             }
         }
+        Ok(())
     }
 
-    fn evaluate_for_stmt(&mut self, name: &Token, start: &Expr, end: &Expr, statement: &Stmt) {
-        let start_value = self.evaluate_expr(start);
-        let end_value = self.evaluate_expr(end);
+    fn evaluate_for_stmt(
+        &mut self,
+        name: &Token,
+        start: &Expr,
+        end: &Expr,
+        statement: &Stmt,
+    ) -> Result<(), Unwind> {
+        let start_value = self.evaluate_expr(start)?;
+        let end_value = self.evaluate_expr(end)?;
 
-        if let (Value::Num(mut current), Value::Num(end)) = (start_value, end_value) {
-            self.env.create_new_child();
-            self.env.insert_value(&name.lexeme, Value::Num(current));
-
-            while current < end {
-                self.evaluate_stmt(statement);
-
-                current += 1.0;
-                if let Err(msg) = self.env.replace_value(name, &Value::Num(current)) {
-                    crash(name.line, &msg)
-                }
-            }
-
-            while current > end {
-                self.evaluate_stmt(statement);
+        if let (Value::Num(current), Value::Num(end)) = (start_value, end_value) {
+            self.evaluate_for_loop(name, current, end, statement)
+        } else {
+            Err(Unwind::error(
+                ErrorKind::Type,
+                &name.span,
+                Code::RangeNonNumber,
+            ))
+        }
+    }
 
-                current -= 1.0;
-                if let Err(msg) = self.env.replace_value(name, &Value::Num(current)) {
-                    crash(name.line, &msg)
-                }
-            }
+    fn evaluate_for_loop(
+        &mut self,
+        name: &Token,
+        mut current: f64,
+        end: f64,
+        statement: &Stmt,
+    ) -> Result<(), Unwind> {
+        let step = if current <= end { 1.0 } else { -1.0 };
+        while (step > 0.0 && current < end) || (step < 0.0 && current > end) {
+            // every round gets its own scope, so closures keep their own value
+            self.env.create_new_child();
+            self.env.insert_value(name.symbol, Value::Num(current));
+            let result = self.evaluate_stmt(statement);
             self.env.kill_youngest_child();
-        } else {
-            panic!("Unreachable.");
+            result.map_err(|unwind| {
+                unwind.trace(
+                    || Text::ForValue.with(&[&name.lexeme, &current]),
+                    &name.span,
+                )
+            })?;
+            current += step;
         }
+        Ok(())
     }
 
-    pub fn evaluate_expr(&mut self, expr: &Expr) -> Value {
+    pub fn evaluate_expr(&mut self, expr: &Expr) -> Result<Value, Unwind> {
         match expr {
-            Expr::Lit(lit) => Value::from_lit(lit),
+            Expr::Lit(lit) => Ok(Value::from_lit(lit)),
             Expr::Grouping(expr) => self.evaluate_expr(expr),
             Expr::Unary(token, expr) => self.evaluate_unary_expr(token, expr),
             Expr::Binary(left, op, right) => self.evaluate_binary_expr(left, op, right),
-            Expr::Var(token) => self.evaluate_var_expr(token),
-            Expr::Assign(name, expr) => self.evaluate_assign_expr(name, expr),
+            Expr::Var(variable) => self.lookup(variable),
+            Expr::Assign(variable, expr) => self.evaluate_assign_expr(variable, expr),
             Expr::Logic(left, op, right) => self.evaluate_logic_expr(left, op, right),
+            Expr::Call(callee, paren, args) => self.evaluate_call_expr(callee, paren, args),
+            Expr::Pipe(left, op, right) => self.evaluate_pipe_expr(left, op, right),
+            Expr::Try(expr, token) => self.evaluate_try_expr(expr, token),
+            Expr::Get(expr, name) => self.evaluate_get_expr(expr, name),
+            Expr::Set(object, name, value) => self.evaluate_set_expr(object, name, value),
+            Expr::This(variable) => self.lookup(variable),
+            // only ever run by the generator, which handles it as a statement
+            Expr::Yield(..) => panic!("Unreachable."),
+            Expr::Lambda(declaration) => {
+                let fun = Function::new(declaration.clone(), self.env.clone());
+                Ok(Value::Func(Rc::new(fun)))
+            }
+            Expr::List(exprs) => {
+                let mut values = Vec::new();
+                for expr in exprs {
+                    values.push(self.evaluate_expr(expr)?);
+                }
+                Ok(Value::from_vec(values))
+            }
+            Expr::Map(pairs) => {
+                let mut values = Vec::new();
+                for (key, value) in pairs {
+                    values.push((self.evaluate_expr(key)?, self.evaluate_expr(value)?));
+                }
+                Ok(Value::from_pairs(values))
+            }
+            Expr::Comprehension(expr, clauses) => {
+                let mut values = Vec::new();
+                self.evaluate_comprehension(clauses, &mut |interpreter| {
+                    values.push(interpreter.evaluate_expr(expr)?);
+                    Ok(())
+                })?;
+                Ok(Value::from_vec(values))
+            }
+            Expr::MapComprehension(key, value, clauses) => {
+                let mut pairs = Vec::new();
+                self.evaluate_comprehension(clauses, &mut |interpreter| {
+                    let key = interpreter.evaluate_expr(key)?;
+                    pairs.push((key, interpreter.evaluate_expr(value)?));
+                    Ok(())
+                })?;
+                Ok(Value::from_pairs(pairs))
+            }
+            Expr::Tuple(exprs) => {
+                let mut values = Vec::new();
+                for expr in exprs {
+                    values.push(self.evaluate_expr(expr)?);
+                }
+                Ok(Value::Tuple(Rc::new(values)))
+            }
+            Expr::Index(expr, bracket, index) => self.evaluate_index_expr(expr, bracket, index),
+            Expr::SetIndex(expr, bracket, index, value) => {
+                self.evaluate_set_index_expr(expr, bracket, index, value)
+            }
         }
     }
 
-    fn evaluate_unary_expr(&mut self, token: &Token, expr: &Box<Expr>) -> Value {
-        let right = self.evaluate_expr(expr);
+    fn evaluate_unary_expr(&mut self, token: &Token, expr: &Expr) -> Result<Value, Unwind> {
+        let right = self.evaluate_expr(expr)?;
 
-        match token.kind {
+        let value = match token.kind {
             TokenType::Minus => match right {
                 Value::Num(num) => Value::Num(-num),
-                _ => crash(
-                    token.line,
-                    "Min kan alleen worden gebruikt voor nummers, kaaskop",
-                ),
+                _ => {
+                    return Err(Unwind::error(
+                        ErrorKind::Type,
+                        &token.span,
+                        Code::NegateNonNumber,
+                    ))
+                }
             },
             TokenType::Bang => match right.is_true() {
                 Some(bool) => Value::from_bool(!bool),
-                None => crash(
-                    token.line,
-                    "Uitroepteken kan alleen worden gebruikt op waarheidswaardes, kaaskop",
-                ),
+                None => {
+                    return Err(Unwind::error(
+                        ErrorKind::Type,
+                        &token.span,
+                        Code::NotNonBool,
+                    ))
+                }
             },
             _ => panic!("Unreachable."),
-        }
+        };
+        Ok(value)
     }
 
-    fn evaluate_binary_expr(&mut self, left: &Box<Expr>, op: &Token, right: &Box<Expr>) -> Value {
-        let left = self.evaluate_expr(left);
-        let right = self.evaluate_expr(right);
+    fn evaluate_binary_expr(
+        &mut self,
+        left: &Expr,
+        op: &Token,
+        right: &Expr,
+    ) -> Result<Value, Unwind> {
+        let left = self.evaluate_expr(left)?;
+        let right = self.evaluate_expr(right)?;
+
+        if let Some(value) = self.evaluate_operator(&left, op, &right)? {
+            return Ok(value);
+        }
 
         macro_rules! apply_arith_to_nums {
             ($type: ident, $op: tt) => {
                 if let (Value::Num(num1), Value::Num(num2)) = (left, right) {
                     Value::Num(num1 $op num2)
                 } else {
-                    crash(op.line, concat!(stringify!($op), " kan alleen worden gebruikt op nummers, kaaskop"))
+                    return Err(Unwind::error(ErrorKind::Type, &op.span, Code::ArithmeticNonNumber.with(&[&stringify!($op)])))
                 }
             };
         }
@@ -170,35 +682,27 @@ impl Interpreter {
                 if let (Value::Num(num1), Value::Num(num2)) = (left, right) {
                     Value::from_bool(num1 $op num2)
                 } else {
-                    crash(op.line, concat!(stringify!($op), " kan alleen worden gebruikt op nummers, kaaskop"));
+                    return Err(Unwind::error(ErrorKind::Type, &op.span, Code::ArithmeticNonNumber.with(&[&stringify!($op)])));
                 }
             };
         }
 
-        match op.kind {
+        let value = match op.kind {
             TokenType::Plus => match (left, right) {
-                (Value::Num(num), Value::Str(str)) => return Value::Str(format!("{}{}", num, str)),
-                (Value::Str(str), Value::Num(num)) => return Value::Str(format!("{}{}", str, num)),
-                (Value::Num(num1), Value::Num(num2)) => return Value::Num(num1 + num2),
-                (Value::Str(str1), Value::Str(str2)) => {
-                    return Value::Str(format!("{}{}", str1, str2))
-                }
+                (Value::Num(num), Value::Str(str)) => Value::Str(format!("{}{}", num, str)),
+                (Value::Str(str), Value::Num(num)) => Value::Str(format!("{}{}", str, num)),
+                (Value::Num(num1), Value::Num(num2)) => Value::Num(num1 + num2),
+                (Value::Str(str1), Value::Str(str2)) => Value::Str(format!("{}{}", str1, str2)),
 
-                _ => crash(
-                    op.line,
-                    "Plus kan alleen worden gebruikt op nummers en strings, kaaskop.",
-                ),
+                _ => return Err(Unwind::error(ErrorKind::Type, &op.span, Code::PlusTypes)),
             },
             TokenType::Minus => apply_arith_to_nums!(Minus, -),
             TokenType::Star => apply_arith_to_nums!(Star, *),
             TokenType::Slash => apply_arith_to_nums!(Slash, /),
 
             TokenType::Caret => match (left, right) {
-                (Value::Num(num1), Value::Num(num2)) => return Value::Num(num1.powf(num2)),
-                _ => crash(
-                    op.line,
-                    "Caret kan alleen worden gebruikt op nummers, kaaskop.",
-                ),
+                (Value::Num(num1), Value::Num(num2)) => Value::Num(num1.powf(num2)),
+                _ => return Err(Unwind::error(ErrorKind::Type, &op.span, Code::CaretTypes)),
             },
 
             TokenType::Greater => apply_logic_to_nums!(Greater, >),
@@ -207,76 +711,1142 @@ impl Interpreter {
             TokenType::LessEqual => apply_logic_to_nums!(LessEqaul, <=),
             TokenType::Equal => apply_logic_to_nums!(Equal, ==),
 
-            TokenType::EqualEqual => Value::from_bool(Value::is_equal(&left, &right)),
-            TokenType::BangEqual => Value::from_bool(!Value::is_equal(&left, &right)),
+            // hond is Hond, hond is Dier, punt implementeert Punt
+            TokenType::Is | TokenType::Implements => match (left, right) {
+                (Value::Instance(instance), Value::ClassType(class)) => {
+                    Value::from_bool(Rc::ptr_eq(&instance.class, &class))
+                }
+                (Value::Instance(instance), Value::TraitType(trait_type)) => {
+                    Value::from_bool(instance.class.implements(&trait_type))
+                }
+                (Value::Record(record), Value::RecordType(kind)) => {
+                    Value::from_bool(Rc::ptr_eq(&record.kind, &kind))
+                }
+                (_, Value::ClassType(_) | Value::TraitType(_) | Value::RecordType(_)) => {
+                    Value::False
+                }
+                (_, right) => {
+                    let msg = Code::IsNeedsType.with(&[&op.lexeme, &right]);
+                    return Err(Unwind::error(ErrorKind::Type, &op.span, msg));
+                }
+            },
             _ => panic!("Unreachable."),
+        };
+        Ok(value)
+    }
+
+    // objects can do + - * / < > <= >= == != with the methods plus, min, keer, gedeeld, kleiner_dan and gelijk
+    fn evaluate_operator(
+        &mut self,
+        left: &Value,
+        op: &Token,
+        right: &Value,
+    ) -> Result<Option<Value>, Unwind> {
+        // the objects can also be inside a list or a record
+        if let TokenType::EqualEqual | TokenType::BangEqual = op.kind {
+            let equal = self.is_equal(left, right, &op.span)?;
+            return Ok(Some(Value::from_bool(
+                equal == (op.kind == TokenType::EqualEqual),
+            )));
+        }
+        if !matches!(left, Value::Instance(_)) && !matches!(right, Value::Instance(_)) {
+            return Ok(None);
+        }
+
+        // a >= b is niet a < b, a > b is b < a and a <= b is niet b < a
+        let (name, receiver, other, negate) = match op.kind {
+            // "tekst" + object uses als_tekst, when the object can't add it itself
+            TokenType::Plus => match (left, right) {
+                (Value::Instance(instance), _) if instance.class.find_method("plus").is_some() => {
+                    ("plus", left, right, false)
+                }
+                (Value::Str(_), _) | (_, Value::Str(_)) => {
+                    let text = format!("{}{}", self.text(left)?, self.text(right)?);
+                    return Ok(Some(Value::Str(text)));
+                }
+                _ => ("plus", left, right, false),
+            },
+            TokenType::Minus => ("min", left, right, false),
+            TokenType::Star => ("keer", left, right, false),
+            TokenType::Slash => ("gedeeld", left, right, false),
+            TokenType::Less => ("kleiner_dan", left, right, false),
+            TokenType::Greater => ("kleiner_dan", right, left, false),
+            TokenType::LessEqual => ("kleiner_dan", right, left, true),
+            TokenType::GreaterEqual => ("kleiner_dan", left, right, true),
+            _ => return Ok(None),
+        };
+
+        let instance = match receiver {
+            Value::Instance(instance) => instance,
+            // 2 * vector can't work, the number doesn't know the method
+            _ => {
+                let msg = Code::OperatorObjectLeft.with(&[&left, &op.lexeme, &right, &name]);
+                return Err(Unwind::error(ErrorKind::Type, &op.span, msg));
+            }
+        };
+        let value = match self.call_operator(instance, name, vec![other.clone()], &op.span)? {
+            Some(value) => value,
+            None => {
+                let msg =
+                    Code::MissingOperatorMethod.with(&[&instance.class.name, &name, &op.lexeme]);
+                return Err(Unwind::error(ErrorKind::Type, &op.span, msg));
+            }
+        };
+
+        if name != "kleiner_dan" {
+            return Ok(Some(value));
+        }
+        match value.is_true() {
+            Some(is_true) => Ok(Some(Value::from_bool(is_true != negate))),
+            None => {
+                let msg = Code::MustReturnBool.with(&[&name, &instance.class.name, &value]);
+                Err(Unwind::error(ErrorKind::Type, &op.span, msg))
+            }
+        }
+    }
+
+    // calls the method of the object, or gives back None when its klas doesn't have it
+    fn call_operator(
+        &mut self,
+        instance: &Rc<Instance>,
+        name: &str,
+        args: Vec<Value>,
+        span: &Span,
+    ) -> Result<Option<Value>, Unwind> {
+        match instance.class.find_method(name) {
+            Some(method) => {
+                let method = Value::Func(Rc::new(bind_method(&method, instance)));
+                self.call(method, args, Vec::new(), span).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    // == that asks 'gelijk' of an object, also when it is inside a list, tuple, map or record
+    fn is_equal(&mut self, left: &Value, right: &Value, span: &Span) -> Result<bool, Unwind> {
+        Value::is_equal_with(left, right, &mut |instance, other| {
+            let value = self.call_operator(instance, "gelijk", vec![other.clone()], span)?;
+            match value.as_ref().map(Value::is_true) {
+                None => Ok(None),
+                Some(Some(is_true)) => Ok(Some(is_true)),
+                Some(None) => {
+                    let msg = Code::MustReturnBool.with(&[
+                        &"gelijk",
+                        &instance.class.name,
+                        &value.unwrap(),
+                    ]);
+                    Err(Unwind::error(ErrorKind::Type, span, msg))
+                }
+            }
+        })
+    }
+
+    // how roep and toon show a value, an object with 'als_tekst' decides for itself
+    fn text(&mut self, value: &Value) -> Result<String, Unwind> {
+        value.to_text_with(&mut |instance| {
+            let Some(method) = instance.class.find_method("als_tekst") else {
+                return Ok(None);
+            };
+            let span = &method.declaration.name.span;
+            match self.call_operator(instance, "als_tekst", Vec::new(), span)? {
+                Some(Value::Str(text)) => Ok(Some(text)),
+                Some(value) => {
+                    let msg = Code::MustReturnText.with(&[&instance.class.name, &value]);
+                    Err(Unwind::error(ErrorKind::Type, span, msg))
+                }
+                None => Ok(None),
+            }
+        })
+    }
+
+    // for natives, which have no line of their own
+    pub fn text_of(&mut self, value: &Value) -> Result<String, Message> {
+        match self.text(value) {
+            Ok(text) => Ok(text),
+            Err(Unwind::Error(error)) => Err(Message {
+                code: error.code,
+                text: error.message,
+            }),
+            Err(Unwind::Return(_) | Unwind::TailCall(_)) => panic!("Unreachable."),
         }
     }
 
-    fn evaluate_logic_expr(&mut self, left: &Box<Expr>, op: &Token, right: &Expr) -> Value {
+    fn evaluate_logic_expr(
+        &mut self,
+        left: &Expr,
+        op: &Token,
+        right: &Expr,
+    ) -> Result<Value, Unwind> {
         match op.kind {
             TokenType::And => {
-                let left = self.evaluate_expr(left).is_true();
+                let left = self.evaluate_expr(left)?.is_true();
 
                 if let Some(left) = left {
-                    let right = self.evaluate_expr(right).is_true();
+                    let right = self.evaluate_expr(right)?.is_true();
 
                     if let Some(right) = right {
-                        return Value::from_bool(left && right);
+                        Ok(Value::from_bool(left && right))
                     } else {
-                        crash(
-                            op.line,
-                            "'en' kan alleen worden gebruikt op waardigheids waarden, kaaskop.",
-                        );
+                        Err(Unwind::error(
+                            ErrorKind::Type,
+                            &op.span,
+                            Code::LogicNonBool.with(&[&"en"]),
+                        ))
                     }
                 } else {
-                    crash(
-                        op.line,
-                        "'en' kan alleen worden gebruikt op waardigheids waarden, kaaskop.",
-                    );
+                    Err(Unwind::error(
+                        ErrorKind::Type,
+                        &op.span,
+                        Code::LogicNonBool.with(&[&"en"]),
+                    ))
                 }
             }
 
             TokenType::Or => {
-                match self.evaluate_expr(left).is_true() {
+                match self.evaluate_expr(left)?.is_true() {
                     Some(left) => {
                         if left {
-                            return Value::True;
+                            return Ok(Value::True);
                         }
                     }
-                    None => crash(
-                        op.line,
-                        "'of' kan alleen worden gebruikt op waardigheids waarden, kaaskop.",
-                    ),
+                    None => {
+                        return Err(Unwind::error(
+                            ErrorKind::Type,
+                            &op.span,
+                            Code::LogicNonBool.with(&[&"of"]),
+                        ))
+                    }
                 }
 
-                match self.evaluate_expr(right).is_true() {
-                    Some(right) => Value::from_bool(right),
-                    None => crash(
-                        op.line,
-                        "'of' kan alleen worden gebruikt op waardigheids waarden, kaaskop.",
-                    ),
+                match self.evaluate_expr(right)?.is_true() {
+                    Some(right) => Ok(Value::from_bool(right)),
+                    None => Err(Unwind::error(
+                        ErrorKind::Type,
+                        &op.span,
+                        Code::LogicNonBool.with(&[&"of"]),
+                    )),
                 }
             }
             _ => panic!("Unreachable."),
         }
     }
 
-    fn evaluate_var_expr(&mut self, token: &Token) -> Value {
-        match self.env.get_value(&token) {
-            Some(value) => value,
-            None => crash(
-                token.line,
-                &format!("'{}' is een onbekende variabele.", token.lexeme),
-            ),
+    // every variable is taken straight from the slot the resolver gave it
+    fn lookup(&self, variable: &Variable) -> Result<Value, Unwind> {
+        match variable.place.get() {
+            Place::Local { depth, slot } => Ok(self.env.get_at(depth, slot)),
+            Place::Global(slot) => match self.env.get_global(slot) {
+                Some(value) => Ok(value),
+                None => Err(Unwind::Error(self.env.unknown(&variable.name))),
+            },
+            Place::Unresolved => panic!("Unreachable."),
+        }
+    }
+
+    fn assign(&mut self, variable: &Variable, value: Value) -> Result<(), Unwind> {
+        match variable.place.get() {
+            Place::Local { depth, slot } => self.env.assign_at(depth, slot, value),
+            Place::Global(slot) => {
+                if !self.env.assign_global(slot, value) {
+                    return Err(Unwind::Error(self.env.unknown(&variable.name)));
+                }
+            }
+            Place::Unresolved => panic!("Unreachable."),
+        }
+        Ok(())
+    }
+
+    fn evaluate_assign_expr(&mut self, variable: &Variable, expr: &Expr) -> Result<Value, Unwind> {
+        let new_value = self.evaluate_expr(expr)?;
+        self.assign(variable, new_value.clone())?;
+        Ok(new_value)
+    }
+
+    fn evaluate_call_expr(
+        &mut self,
+        callee: &Expr,
+        paren: &Token,
+        args: &[Arg],
+    ) -> Result<Value, Unwind> {
+        let callee = self.evaluate_expr(callee)?;
+
+        let mut values = Vec::new();
+        let mut named = Vec::new();
+        self.evaluate_args(args, &mut values, &mut named)?;
+        self.call(callee, values, named, &paren.span)
+    }
+
+    fn evaluate_args(
+        &mut self,
+        args: &[Arg],
+        values: &mut Vec<Value>,
+        named: &mut Vec<(Token, Value)>,
+    ) -> Result<(), Unwind> {
+        for arg in args {
+            match arg {
+                Arg::Positional(expr) => values.push(self.evaluate_expr(expr)?),
+                Arg::Spread(dots, expr) => match self.evaluate_expr(expr)?.iter_values() {
+                    Some(items) => values.extend(items),
+                    None => {
+                        return Err(Unwind::error(
+                            ErrorKind::Type,
+                            &dots.span,
+                            Code::SpreadNonList,
+                        ))
+                    }
+                },
+                Arg::Named(name, expr) => {
+                    let value = self.evaluate_expr(expr)?;
+                    named.push((name.clone(), value));
+                }
+            }
         }
+        Ok(())
+    }
+
+    fn evaluate_pipe_expr(
+        &mut self,
+        left: &Expr,
+        op: &Token,
+        right: &Expr,
+    ) -> Result<Value, Unwind> {
+        let mut values = vec![self.evaluate_expr(left)?];
+        let mut named = Vec::new();
+
+        // 'x |> f(a)' calls f(x, a), and 'x |> f' calls f(x)
+        let callee = match right {
+            Expr::Call(callee, _, args) => {
+                let callee = self.evaluate_expr(callee)?;
+                self.evaluate_args(args, &mut values, &mut named)?;
+                callee
+            }
+            right => self.evaluate_expr(right)?,
+        };
+        self.call(callee, values, named, &op.span)
     }
 
-    fn evaluate_assign_expr(&mut self, name: &Token, expr: &Expr) -> Value {
-        let new_value = self.evaluate_expr(expr);
-        if let Err(msg) = self.env.replace_value(name, &new_value) {
-            crash(name.line, &msg)
+    // also used by natives, which report the error at the line of their own call
+    pub fn call_value(&mut self, callee: &Value, args: Vec<Value>) -> Result<Value, Message> {
+        let span = self.native_span.clone();
+        match self.call(callee.clone(), args, Vec::new(), &span) {
+            Ok(value) => Ok(value),
+            Err(Unwind::Error(error)) => Err(self.hand_to_native(error)),
+            Err(Unwind::Return(_) | Unwind::TailCall(_)) => panic!("Unreachable."),
         }
-        new_value
+    }
+
+    // the whole error behind the message a native got, for a native that keeps it to give again
+    pub fn take_error(&mut self, message: Message) -> RoxError {
+        match self.callback_error.take() {
+            Some(error) if error.message == message.text => error,
+            _ => RoxError::run(ErrorKind::Runtime, &self.native_span, message),
+        }
+    }
+
+    pub fn hand_to_native(&mut self, error: RoxError) -> Message {
+        let message = Message {
+            code: error.code,
+            text: error.message.clone(),
+        };
+        self.callback_error = Some(error);
+        message
+    }
+
+    // tail calls come back here instead of being made by the proces that is returning
+    fn call(
+        &mut self,
+        callee: Value,
+        args: Vec<Value>,
+        named: Vec<(Token, Value)>,
+        span: &Span,
+    ) -> Result<Value, Unwind> {
+        let mut call = TailCall {
+            callee,
+            args,
+            named,
+            span: span.clone(),
+        };
+        loop {
+            match self.call_with_named(&call.callee, call.args, call.named, &call.span) {
+                Err(Unwind::TailCall(next)) => call = *next,
+                result => return result,
+            }
+        }
+    }
+
+    fn call_with_named(
+        &mut self,
+        callee: &Value,
+        args: Vec<Value>,
+        named: Vec<(Token, Value)>,
+        span: &Span,
+    ) -> Result<Value, Unwind> {
+        let error = |msg| Unwind::error(ErrorKind::Arguments, span, msg);
+        match callee {
+            Value::Func(fun) => self.call_function(fun, args, named, span),
+            Value::Native(native) => {
+                if !named.is_empty() {
+                    return Err(error(Code::NativeNamedArgs.with(&[&native.name])));
+                }
+                check_arity(native.name, native.min_arity, native.max_arity, args.len())
+                    .map_err(error)?;
+                self.callback_error = None;
+                let outer = mem::replace(&mut self.native_span, span.clone());
+                let result = (native.fun)(self, &args);
+                self.native_span = outer;
+                result.map_err(|msg| match self.callback_error.take() {
+                    // the error came from a proces the native called, so its trace is kept
+                    Some(error) if error.message == msg.text => {
+                        Unwind::Error(error).trace(|| Text::NativeFrame.with(&[&native.name]), span)
+                    }
+                    _ => Unwind::error(ErrorKind::Runtime, span, msg),
+                })
+            }
+            Value::ClassType(class) => {
+                let instance = Rc::new(Instance::new(class.clone()));
+                match class.find_method("init") {
+                    Some(init) => {
+                        let init = bind_method(&init, &instance);
+                        instance.initializing.set(true);
+                        let result = self.call_function(&init, args, named, span);
+                        instance.initializing.set(false);
+                        // whatever init gives back, calling the klas gives back the object
+                        if let Err(Unwind::TailCall(call)) = result {
+                            self.call(call.callee, call.args, call.named, &call.span)?;
+                        } else {
+                            result?;
+                        }
+                    }
+                    None => {
+                        check_arity(&class.name, 0, 0, args.len() + named.len()).map_err(error)?;
+                    }
+                }
+                Ok(Value::Instance(instance))
+            }
+            Value::RecordType(kind) => {
+                let arity = kind.fields.len();
+                let (slots, _) = match_args(&kind.name, &kind.fields, arity, false, args, named)
+                    .map_err(error)?;
+                let mut values = Vec::new();
+                for (field, slot) in kind.fields.iter().zip(slots) {
+                    match slot {
+                        Some(value) => values.push(value),
+                        None => return Err(error(missing_value(&kind.name, field))),
+                    }
+                }
+                Ok(Value::Record(Rc::new(Record::new(kind.clone(), values))))
+            }
+            _ => Err(Unwind::error(
+                ErrorKind::Type,
+                span,
+                Code::NotCallable.with(&[&callee]),
+            )),
+        }
+    }
+
+    fn call_function(
+        &mut self,
+        fun: &Function,
+        args: Vec<Value>,
+        named: Vec<(Token, Value)>,
+        span: &Span,
+    ) -> Result<Value, Unwind> {
+        self.check_recursion(span)?;
+
+        // the rest parameter is always the last one
+        let params = &fun.declaration.params;
+        let (params, rest) = match params.split_last() {
+            Some((last, others)) if last.rest => (others, Some(last)),
+            _ => (&params[..], None),
+        };
+        let names: Vec<String> = params.iter().map(|p| p.name.lexeme.clone()).collect();
+        let required = params.iter().filter(|p| p.default.is_none()).count();
+        let (slots, rest_values) =
+            match_args(fun.name(), &names, required, rest.is_some(), args, named)
+                .map_err(|msg| Unwind::error(ErrorKind::Arguments, span, msg))?;
+
+        let previous = mem::replace(&mut self.env, fun.closure.with_child());
+        self.call_depth += 1;
+
+        let result = match self.bind_params(fun.name(), params, slots, span) {
+            Ok(None) => {
+                if let Some(rest) = rest {
+                    self.env
+                        .insert_value(rest.name.symbol, Value::from_vec(rest_values));
+                }
+                if fun.declaration.generator {
+                    // the body only starts running when the first value is asked for
+                    let body = fun.declaration.body.clone().into();
+                    let generator = Generator::new(fun.name().to_string(), body, self.env.clone());
+                    Ok(Value::Generator(Rc::new(RefCell::new(generator))))
+                } else {
+                    match self.evaluate_stmts(&fun.declaration.body) {
+                        Ok(()) => Ok(Value::Nil),
+                        Err(Unwind::Return(value)) => Ok(value),
+                        Err(unwind) => Err(unwind),
+                    }
+                }
+            }
+            // a '?' in a default value returned early
+            Ok(Some(value)) => Ok(value),
+            Err(unwind) => Err(unwind),
+        }
+        .map_err(|unwind| unwind.trace(|| Text::ProcesFrame.with(&[&fun.name()]), span));
+
+        self.call_depth -= 1;
+        self.env = previous;
+        result
+    }
+
+    fn check_recursion(&self, span: &Span) -> Result<(), Unwind> {
+        let used = stack_position().abs_diff(self.stack_start);
+        if self.call_depth >= self.recursion_limit || used > STACK_SIZE - STACK_RESERVE {
+            let msg = Code::RecursionTooDeep.with(&[&self.call_depth]);
+            return Err(Unwind::error(ErrorKind::Recursion, span, msg));
+        }
+        Ok(())
+    }
+
+    // also used by natives, which report the error at the line of their own call
+    pub fn resume_generator(
+        &mut self,
+        generator: &Rc<RefCell<Generator>>,
+        sent: Value,
+    ) -> Result<Option<Value>, Message> {
+        let span = self.native_span.clone();
+        match self.resume(generator, sent, &span) {
+            Ok(value) => Ok(value),
+            Err(Unwind::Error(error)) => Err(self.hand_to_native(error)),
+            Err(Unwind::Return(_) | Unwind::TailCall(_)) => panic!("Unreachable."),
+        }
+    }
+
+    // runs the generator until the next 'lever', and gives back niks when it is done
+    fn resume(
+        &mut self,
+        generator: &Rc<RefCell<Generator>>,
+        sent: Value,
+        span: &Span,
+    ) -> Result<Option<Value>, Unwind> {
+        let (env, target) = {
+            let mut generator = generator.borrow_mut();
+            if generator.running {
+                let msg = Code::GeneratorRunning.with(&[&generator.name]);
+                return Err(Unwind::error(ErrorKind::Runtime, span, msg));
+            }
+            if generator.is_done() {
+                return Ok(None);
+            }
+            // checked before it is marked as running, a generator that was too deep can run later
+            self.check_recursion(span)?;
+            generator.running = true;
+            (generator.env.clone(), generator.target.take())
+        };
+
+        let previous = mem::replace(&mut self.env, env);
+        self.call_depth += 1;
+        let result = match self.run_generator(generator, target, sent) {
+            // a '?' in a 'lever' or a loop condition ends the generator, like a 'retour' does
+            Err(Unwind::Return(value)) => {
+                generator.borrow_mut().returned = value;
+                Ok(None)
+            }
+            result => result,
+        }
+        .map_err(|unwind| {
+            unwind.trace(
+                || Text::GeneratorFrame.with(&[&generator.borrow().name]),
+                span,
+            )
+        });
+        self.call_depth -= 1;
+
+        let mut generator = generator.borrow_mut();
+        generator.running = false;
+        generator.env = mem::replace(&mut self.env, previous);
+        if !matches!(result, Ok(Some(_))) {
+            generator.frames.clear();
+        }
+        result
+    }
+
+    fn run_generator(
+        &mut self,
+        generator: &Rc<RefCell<Generator>>,
+        target: Option<Target>,
+        sent: Value,
+    ) -> Result<Option<Value>, Unwind> {
+        match target {
+            Some(Target::Declare(name)) => self.env.insert_value(name.symbol, sent),
+            Some(Target::Assign(variable)) => self.assign(&variable, sent)?,
+            None => (),
+        }
+
+        // the frame on top is taken off while it runs, and is put back when it isn't done yet
+        loop {
+            let frame = generator.borrow_mut().frames.pop();
+            let Some(frame) = frame else {
+                return Ok(None);
+            };
+            let push = |frame| generator.borrow_mut().frames.push(frame);
+
+            match frame {
+                Frame::Block {
+                    stmts,
+                    index,
+                    scoped,
+                } => {
+                    if index == stmts.len() {
+                        if scoped {
+                            self.env.kill_youngest_child();
+                        }
+                        continue;
+                    }
+                    push(Frame::Block {
+                        stmts: stmts.clone(),
+                        index: index + 1,
+                        scoped,
+                    });
+                    if let Some(value) = self.run_generator_stmt(generator, &stmts[index])? {
+                        return Ok(Some(value));
+                    }
+                }
+                Frame::While { condition, body } => {
+                    if let Value::True = self.evaluate_expr(&condition)? {
+                        push(Frame::While {
+                            condition,
+                            body: body.clone(),
+                        });
+                        push(block(body, false));
+                    }
+                }
+                Frame::For {
+                    name,
+                    current,
+                    end,
+                    step,
+                    body,
+                } => {
+                    if (step > 0.0 && current < end) || (step < 0.0 && current > end) {
+                        self.env.create_new_child();
+                        self.env.insert_value(name.symbol, Value::Num(current));
+                        push(Frame::For {
+                            name,
+                            current: current + step,
+                            end,
+                            step,
+                            body: body.clone(),
+                        });
+                        push(block(body, true));
+                    }
+                }
+                Frame::ForIn {
+                    pattern,
+                    mut source,
+                    body,
+                    span,
+                } => {
+                    if let Some(value) = self.next_value(&mut source, &span)? {
+                        self.env.create_new_child();
+                        self.bind_pattern(&pattern, value)?;
+                        push(Frame::ForIn {
+                            pattern,
+                            source,
+                            body: body.clone(),
+                            span,
+                        });
+                        push(block(body, true));
+                    }
+                }
+            }
+        }
+    }
+
+    // gives back the value of a 'lever', statements that contain one are turned into frames
+    fn run_generator_stmt(
+        &mut self,
+        generator: &Rc<RefCell<Generator>>,
+        stmt: &Stmt,
+    ) -> Result<Option<Value>, Unwind> {
+        let push = |frame| generator.borrow_mut().frames.push(frame);
+
+        if !stmt.yields() {
+            let result = match self.evaluate_stmt(stmt) {
+                Ok(()) => return Ok(None),
+                Err(Unwind::TailCall(call)) => {
+                    self.call(call.callee, call.args, call.named, &call.span)
+                }
+                Err(unwind) => Err(unwind),
+            };
+            // a 'retour' ends the generator, only a task uses the value it gives back
+            match result {
+                Ok(value) | Err(Unwind::Return(value)) => {
+                    let mut generator = generator.borrow_mut();
+                    generator.frames.clear();
+                    generator.returned = value;
+                    return Ok(None);
+                }
+                Err(unwind) => return Err(unwind),
+            }
+        }
+
+        match stmt {
+            Stmt::Expr(Expr::Yield(_, value)) => {
+                return Ok(Some(self.evaluate_expr(value)?));
+            }
+            Stmt::Var(name, Expr::Yield(_, value)) => {
+                let value = self.evaluate_expr(value)?;
+                generator.borrow_mut().target = Some(Target::Declare(name.clone()));
+                return Ok(Some(value));
+            }
+            Stmt::Expr(Expr::Assign(name, value)) => {
+                if let Expr::Yield(_, value) = &**value {
+                    let value = self.evaluate_expr(value)?;
+                    generator.borrow_mut().target = Some(Target::Assign(name.clone()));
+                    return Ok(Some(value));
+                }
+            }
+            Stmt::Block(statements) => {
+                let scoped = statements.iter().any(Stmt::declares);
+                if scoped {
+                    self.env.create_new_child();
+                }
+                push(Frame::Block {
+                    stmts: statements.clone().into(),
+                    index: 0,
+                    scoped,
+                });
+            }
+            Stmt::If(first_if, else_ifs, other) => {
+                for i in std::iter::once(first_if).chain(else_ifs) {
+                    if let Value::True = self.evaluate_expr(&i.should_execute)? {
+                        push(block(single(&i.statement), false));
+                        return Ok(None);
+                    }
+                }
+                if let Some(other) = other {
+                    push(block(single(other), false));
+                }
+            }
+            Stmt::While(_, condition, statement) => push(Frame::While {
+                condition: Rc::new(condition.clone()),
+                body: single(statement),
+            }),
+            Stmt::For(name, start, end, statement) => {
+                let (current, end) = match (self.evaluate_expr(start)?, self.evaluate_expr(end)?) {
+                    (Value::Num(current), Value::Num(end)) => (current, end),
+                    _ => {
+                        let msg = Code::RangeNonNumber;
+                        return Err(Unwind::error(ErrorKind::Type, &name.span, msg));
+                    }
+                };
+                push(Frame::For {
+                    name: name.clone(),
+                    current,
+                    end,
+                    step: if current <= end { 1.0 } else { -1.0 },
+                    body: single(statement),
+                });
+            }
+            Stmt::ForIn(pattern, keyword, iterable, statement) => {
+                let iterable = self.evaluate_expr(iterable)?;
+                push(Frame::ForIn {
+                    pattern: Rc::new(pattern.clone()),
+                    source: iteration(iterable, &keyword.span)?,
+                    body: single(statement),
+                    span: keyword.span.clone(),
+                });
+            }
+            _ => panic!("Unreachable."),
+        }
+        Ok(None)
+    }
+
+    fn next_value(&mut self, source: &mut Iteration, span: &Span) -> Result<Option<Value>, Unwind> {
+        match source {
+            Iteration::Values(values) => Ok(values.next()),
+            Iteration::Generator(generator) => {
+                let generator = generator.clone();
+                self.resume(&generator, Value::Nil, span)
+            }
+        }
+    }
+
+    // defaults are evaluated inside the call, so they can use the parameters before them
+    fn bind_params(
+        &mut self,
+        name: &str,
+        params: &[Param],
+        slots: Vec<Option<Value>>,
+        span: &Span,
+    ) -> Result<Option<Value>, Unwind> {
+        for (param, slot) in params.iter().zip(slots) {
+            let value = match (slot, &param.default) {
+                (Some(value), _) => value,
+                (None, Some(default)) => match self.evaluate_expr(default) {
+                    Ok(value) => value,
+                    Err(Unwind::Return(value)) => return Ok(Some(value)),
+                    Err(unwind) => return Err(unwind),
+                },
+                (None, None) => {
+                    let msg = missing_value(name, &param.name.lexeme);
+                    return Err(Unwind::error(ErrorKind::Arguments, span, msg));
+                }
+            };
+            self.env.insert_value(param.name.symbol, value);
+        }
+        Ok(None)
+    }
+
+    // 'vast' fields can only be set while init runs
+    fn evaluate_set_expr(
+        &mut self,
+        object: &Expr,
+        name: &Token,
+        value: &Expr,
+    ) -> Result<Value, Unwind> {
+        let object = self.evaluate_expr(object)?;
+        let value = self.evaluate_expr(value)?;
+        match object {
+            Value::Instance(instance) => {
+                if instance.class.consts.contains(&name.lexeme) && !instance.initializing.get() {
+                    let msg = Code::ConstAssign.with(&[&name.lexeme]);
+                    return Err(Unwind::error(ErrorKind::Const, &name.span, msg));
+                }
+                (instance.fields.borrow_mut()).insert(name.lexeme.clone(), value.clone());
+                Ok(value)
+            }
+            object => {
+                let msg = Code::SetFieldOnNonObject.with(&[&object]);
+                Err(Unwind::error(ErrorKind::Type, &name.span, msg))
+            }
+        }
+    }
+
+    fn evaluate_get_expr(&mut self, expr: &Expr, name: &Token) -> Result<Value, Unwind> {
+        let value = self.evaluate_expr(expr)?;
+        get_field(&value, name)
+    }
+
+    // the loop variables live in their own scope, so they don't leak out of the comprehension
+    fn evaluate_comprehension(
+        &mut self,
+        clauses: &[Clause],
+        each: &mut dyn FnMut(&mut Interpreter) -> Result<(), Unwind>,
+    ) -> Result<(), Unwind> {
+        self.env.create_new_child();
+        let result = self.evaluate_clauses(clauses, each);
+        self.env.kill_youngest_child();
+        result
+    }
+
+    fn evaluate_clauses(
+        &mut self,
+        clauses: &[Clause],
+        each: &mut dyn FnMut(&mut Interpreter) -> Result<(), Unwind>,
+    ) -> Result<(), Unwind> {
+        let (clause, rest) = match clauses.split_first() {
+            Some(split) => split,
+            None => return each(self),
+        };
+
+        match clause {
+            Clause::For(pattern, start, end) => {
+                let values = self.evaluate_iterable(pattern, start, end)?;
+                for value in values {
+                    self.bind_pattern(pattern, value)?;
+                    self.evaluate_clauses(rest, each)?;
+                }
+            }
+            Clause::If(keyword, expr) => match self.evaluate_expr(expr)? {
+                Value::True => self.evaluate_clauses(rest, each)?,
+                Value::False => (),
+                value => {
+                    let msg = Code::ConditionNotBool.with(&[&value]);
+                    return Err(Unwind::error(ErrorKind::Type, &keyword.span, msg));
+                }
+            },
+        }
+        Ok(())
+    }
+
+    // 'in lijst' or 'in 0 tot 10', which counts just like a 'voor' loop
+    fn evaluate_iterable(
+        &mut self,
+        pattern: &Pattern,
+        start: &Expr,
+        end: &Option<Expr>,
+    ) -> Result<Vec<Value>, Unwind> {
+        let span = pattern
+            .names()
+            .first()
+            .map_or(Span::default(), |name| name.span.clone());
+        let span = &span;
+        let start = self.evaluate_expr(start)?;
+
+        let end = match end {
+            Some(end) => self.evaluate_expr(end)?,
+            None => {
+                let mut source = iteration(start, span)?;
+                let mut values = Vec::new();
+                while let Some(value) = self.next_value(&mut source, span)? {
+                    values.push(value);
+                }
+                return Ok(values);
+            }
+        };
+
+        match (start, end) {
+            (Value::Num(mut current), Value::Num(end)) => {
+                let mut values = Vec::new();
+                while current < end {
+                    values.push(Value::Num(current));
+                    current += 1.0;
+                }
+                while current > end {
+                    values.push(Value::Num(current));
+                    current -= 1.0;
+                }
+                Ok(values)
+            }
+            _ => Err(Unwind::error(ErrorKind::Type, span, Code::RangeNonNumber)),
+        }
+    }
+
+    fn evaluate_index_expr(
+        &mut self,
+        expr: &Expr,
+        bracket: &Token,
+        index: &Expr,
+    ) -> Result<Value, Unwind> {
+        let value = self.evaluate_expr(expr)?;
+        let index = self.evaluate_expr(index)?;
+
+        let value = match value {
+            Value::List(list) => {
+                let list = list.borrow();
+                let i = check_index(&index, list.len(), bracket)?;
+                list[i].clone()
+            }
+            Value::Tuple(tuple) => {
+                let i = check_index(&index, tuple.len(), bracket)?;
+                tuple[i].clone()
+            }
+            Value::Str(str) => {
+                let chars: Vec<char> = str.chars().collect();
+                let i = check_index(&index, chars.len(), bracket)?;
+                Value::Str(chars[i].to_string())
+            }
+            Value::Map(map) => match map_get(&map.borrow(), &index) {
+                Some(value) => value,
+                None => {
+                    let msg = Code::KeyMissing.with(&[&index]);
+                    return Err(Unwind::error(ErrorKind::Index, &bracket.span, msg));
+                }
+            },
+            value => {
+                let msg = Code::NotIndexable.with(&[&value]);
+                return Err(Unwind::error(ErrorKind::Type, &bracket.span, msg));
+            }
+        };
+        Ok(value)
+    }
+
+    fn evaluate_set_index_expr(
+        &mut self,
+        expr: &Expr,
+        bracket: &Token,
+        index: &Expr,
+        value: &Expr,
+    ) -> Result<Value, Unwind> {
+        let list = self.evaluate_expr(expr)?;
+        let index = self.evaluate_expr(index)?;
+        let value = self.evaluate_expr(value)?;
+
+        match list {
+            Value::List(list) => {
+                let mut list = list.borrow_mut();
+                let i = check_index(&index, list.len(), bracket)?;
+                list[i] = value.clone();
+            }
+            Value::Map(map) => map_insert(&mut map.borrow_mut(), index, value.clone()),
+            Value::Tuple(_) => {
+                return Err(Unwind::error(
+                    ErrorKind::Type,
+                    &bracket.span,
+                    Code::TupleImmutable,
+                ))
+            }
+            list => {
+                let msg = Code::NotAList.with(&[&list]);
+                return Err(Unwind::error(ErrorKind::Type, &bracket.span, msg));
+            }
+        }
+        Ok(value)
+    }
+
+    // 'waarde?' gives back what is inside an Ok, and returns a Fout from the current proces
+    fn evaluate_try_expr(&mut self, expr: &Expr, token: &Token) -> Result<Value, Unwind> {
+        match self.evaluate_expr(expr)? {
+            Value::Ok(value) => Ok(*value),
+            Value::Fout(err) => {
+                if self.call_depth == 0 {
+                    let msg = Code::UnhandledFout.with(&[&err]);
+                    return Err(Unwind::error(ErrorKind::Runtime, &token.span, msg));
+                }
+                Err(Unwind::Return(Value::Fout(err)))
+            }
+            value => Err(Unwind::error(
+                ErrorKind::Type,
+                &token.span,
+                Code::QuestionOnNonResult.with(&[&value]),
+            )),
+        }
+    }
+}
+
+fn get_field(value: &Value, name: &Token) -> Result<Value, Unwind> {
+    let msg = match value {
+        Value::Module(module) => match module.exports.get(&name.lexeme) {
+            Some(value) => return Ok(value.clone()),
+            None => Code::ModuleMissingExport.with(&[&module.name, &name.lexeme]),
+        },
+        Value::Map(map) => match map_get(&map.borrow(), &Value::Str(name.lexeme.clone())) {
+            Some(value) => return Ok(value),
+            None => Code::KeyMissing.with(&[&name.lexeme]),
+        },
+        Value::Record(record) => match record.get(&name.lexeme) {
+            Some(value) => return Ok(value.clone()),
+            None => Code::RecordMissingField.with(&[&record.kind.name, &name.lexeme]),
+        },
+        // fields come before methods
+        Value::Instance(instance) => match instance.get(&name.lexeme) {
+            Some(value) => return Ok(value),
+            None => match instance.class.find_method(&name.lexeme) {
+                Some(method) => return Ok(Value::Func(Rc::new(bind_method(&method, instance)))),
+                None => Code::NoFieldOrMethod.with(&[&instance.class.name, &name.lexeme]),
+            },
+        },
+        value => {
+            let msg = Code::NoProperty.with(&[&value, &name.lexeme]);
+            return Err(Unwind::error(ErrorKind::Type, &name.span, msg));
+        }
+    };
+    Err(Unwind::error(ErrorKind::Name, &name.span, msg))
+}
+
+// the address of a local, which moves further away from the start with every nested call
+#[inline(never)]
+fn stack_position() -> usize {
+    let here = 0u8;
+    std::ptr::addr_of!(here) as usize
+}
+
+// a method that is taken from an object remembers it as 'dit'
+fn bind_method(method: &Function, instance: &Rc<Instance>) -> Function {
+    let mut closure = method.closure.with_child();
+    closure.insert_value(Symbol::intern("dit"), Value::Instance(instance.clone()));
+    Function::new(method.declaration.clone(), closure)
+}
+
+fn check_arity(name: &str, min: usize, max: usize, got: usize) -> Result<(), Message> {
+    if got < min || got > max {
+        let expected = if min == max {
+            min.to_string()
+        } else if max == usize::MAX {
+            Text::AtLeast.with(&[&min])
+        } else {
+            Text::Between.with(&[&min, &max])
+        };
+        return Err(Code::ArityMismatch.with(&[&name, &expected, &got]));
+    }
+    Ok(())
+}
+
+fn iteration(value: Value, span: &Span) -> Result<Iteration, Unwind> {
+    match value {
+        Value::Generator(generator) => Ok(Iteration::Generator(generator)),
+        value => match value.iter_values() {
+            Some(values) => Ok(Iteration::Values(values.into_iter())),
+            None => {
+                let msg = Code::NotIterable.with(&[&value]);
+                Err(Unwind::error(ErrorKind::Type, span, msg))
+            }
+        },
+    }
+}
+
+fn block(stmts: Rc<[Stmt]>, scoped: bool) -> Frame {
+    Frame::Block {
+        stmts,
+        index: 0,
+        scoped,
+    }
+}
+
+// the body of an if or a loop, as a block without its own scope
+fn single(stmt: &Stmt) -> Rc<[Stmt]> {
+    Rc::new([stmt.clone()])
+}
+
+// puts the arguments of a call in the slots of the parameters, first by position and then by name
+fn match_args(
+    name: &str,
+    params: &[String],
+    required: usize,
+    has_rest: bool,
+    args: Vec<Value>,
+    named: Vec<(Token, Value)>,
+) -> Result<(Vec<Option<Value>>, Vec<Value>), Message> {
+    if !has_rest && args.len() > params.len() || named.is_empty() && args.len() < required {
+        let max = if has_rest { usize::MAX } else { params.len() };
+        check_arity(name, required, max, args.len())?;
+    }
+
+    let mut slots: Vec<Option<Value>> = vec![None; params.len()];
+    let mut rest = Vec::new();
+    for (i, arg) in args.into_iter().enumerate() {
+        match slots.get_mut(i) {
+            Some(slot) => *slot = Some(arg),
+            None => rest.push(arg),
+        }
+    }
+
+    for (param, value) in named {
+        match params.iter().position(|p| *p == param.lexeme) {
+            Some(i) if slots[i].is_some() => {
+                return Err(Code::DuplicateArgument.with(&[&name, &param.lexeme]))
+            }
+            Some(i) => slots[i] = Some(value),
+            None => return Err(Code::UnknownParameter.with(&[&name, &param.lexeme])),
+        }
+    }
+    Ok((slots, rest))
+}
+
+fn missing_value(name: &str, param: &str) -> Message {
+    Code::MissingArgument.with(&[&name, &param])
+}
+
+fn check_index(index: &Value, len: usize, bracket: &Token) -> Result<usize, Unwind> {
+    match index {
+        Value::Num(num) if num.fract() == 0.0 && *num >= 0.0 && (*num as usize) < len => {
+            Ok(*num as usize)
+        }
+        Value::Num(num) => {
+            let msg = Code::IndexOutOfRange.with(&[&num, &len]);
+            Err(Unwind::error(ErrorKind::Index, &bracket.span, msg))
+        }
+        index => {
+            let msg = Code::InvalidIndex.with(&[&index]);
+            Err(Unwind::error(ErrorKind::Type, &bracket.span, msg))
+        }
+    }
+}
+
+fn file_name(path: &Path) -> String {
+    match path.file_name() {
+        Some(name) => name.to_string_lossy().to_string(),
+        None => path.display().to_string(),
     }
 }
@@ -0,0 +1,84 @@
+use crate::{
+    error::{RoxError, Severity, Span},
+    messages::{Code, Text},
+};
+
+// the warnings Rox knows, the rest of the codes are errors
+pub const WARNINGS: &[Code] = &[
+    Code::UnusedVariable,
+    Code::ShadowedLoopVariable,
+    Code::UnreachableAfterReturn,
+    Code::AlwaysFalseComparison,
+    Code::EmptyLoopBody,
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Level {
+    Allow,
+    Deny,
+}
+
+// // rox: sta_toe(R0501) or // rox: verbied(R0501, R0503), for the whole file
+#[derive(Debug, Clone)]
+pub struct Pragma {
+    pub level: Level,
+    pub code: Code,
+    pub span: Span,
+}
+impl Pragma {
+    pub fn new(level: Level, code: Code, span: Span) -> Self {
+        Self { level, code, span }
+    }
+
+    // the text of the comment after 'rox:'
+    pub fn parse(text: &str, span: &Span) -> Result<Vec<Pragma>, RoxError> {
+        let unknown = || RoxError::scan(span, Code::UnknownPragma.with(&[&text.trim()]));
+        let (level, codes) = text.trim().split_once('(').ok_or_else(unknown)?;
+        let level = match level.trim() {
+            "sta_toe" => Level::Allow,
+            "verbied" => Level::Deny,
+            _ => return Err(unknown()),
+        };
+        let codes = codes.trim_end().strip_suffix(')').ok_or_else(unknown)?;
+
+        let mut pragmas = Vec::new();
+        for id in codes.split(',').map(str::trim) {
+            match Code::from_id(id).filter(|code| WARNINGS.contains(code)) {
+                Some(code) => pragmas.push(Pragma::new(level, code, span.clone())),
+                None => return Err(RoxError::scan(span, Code::NotAWarning.with(&[&id]))),
+            }
+        }
+        Ok(pragmas)
+    }
+}
+
+// drops the allowed warnings and makes the denied ones errors, a pragma wins over --deny-warnings
+// gives back the warnings to show and the errors
+pub fn sort(
+    warnings: Vec<RoxError>,
+    pragmas: &[Pragma],
+    deny_warnings: bool,
+) -> (Vec<RoxError>, Vec<RoxError>) {
+    let mut shown = Vec::new();
+    let mut denied = Vec::new();
+    for mut warning in warnings {
+        // the last pragma for a code counts, like a later setting overrides an earlier one
+        match pragmas
+            .iter()
+            .rev()
+            .find(|pragma| pragma.code == warning.code)
+        {
+            Some(pragma) if pragma.level == Level::Allow => (),
+            Some(pragma) => {
+                warning.severity = Severity::Error;
+                denied.push(warning.with_label(&pragma.span, Text::DeniedHere.to_string()));
+            }
+            None if deny_warnings => {
+                warning.severity = Severity::Error;
+                denied.push(warning.with_help(Some(Text::DeniedByFlag.to_string())));
+            }
+            None => shown.push(warning),
+        }
+    }
+    (shown, denied)
+}
@@ -1,28 +1,105 @@
-use std::env;
+use std::{env, panic, process, thread};
 
-use colored::Colorize;
+use error::report;
+use interpreter::STACK_SIZE;
+use messages::{Locale, Text};
 use rox::Rox;
 
+mod class;
 mod environment;
+mod error;
+mod explain;
 mod expr;
+mod function;
+mod generator;
 mod interpreter;
-mod rox;
+mod lint;
+mod messages;
+mod module;
+mod natives;
 mod parser;
+mod record;
+mod resolver;
+mod rox;
 mod scanner;
+mod scheduler;
 mod stmt;
+mod suggest;
+mod symbol;
 mod token;
 mod token_type;
 mod value;
 
 fn main() {
+    let interpreter = thread::Builder::new()
+        .stack_size(STACK_SIZE)
+        .spawn(run)
+        .expect("could not start the interpreter");
+
+    match interpreter.join() {
+        Ok(code) => process::exit(code),
+        // a panic is a bug in Rox itself, not in the script, so it is passed on
+        Err(err) => panic::resume_unwind(err),
+    }
+}
+
+// gives back the exit code of the process
+fn run() -> i32 {
     let mut lox = Rox::new();
 
-    let arguments: Vec<String> = env::args().collect();
+    let mut arguments: Vec<String> = env::args().collect();
     //env::set_var("RUST_BACKTRACE", "1");
 
-    if arguments.len() == 1 {
+    // the language of the messages, '--taal=' wins over ROX_TAAL
+    if let Some(locale) = env::var("ROX_TAAL")
+        .ok()
+        .and_then(|name| Locale::parse(&name))
+    {
+        messages::set_locale(locale);
+    }
+
+    // options start with '--' and can be anywhere, the rest works like before
+    let (options, rest): (Vec<String>, Vec<String>) = arguments
+        .drain(..)
+        .partition(|argument| argument.starts_with("--"));
+    arguments = rest;
+    for option in options {
+        if option == "--deny-warnings" {
+            lox.deny_warnings = true;
+            continue;
+        }
+        match option.split_once('=') {
+            Some(("--max-fouten", number)) => match number.parse() {
+                Ok(number) if number > 0 => lox.max_errors = number,
+                _ => {
+                    eprintln!("{}", Text::InvalidMaxErrors.with(&[&number]));
+                    return 64;
+                }
+            },
+            Some(("--taal", name)) => match Locale::parse(name) {
+                Some(locale) => messages::set_locale(locale),
+                None => {
+                    eprintln!("{}", Text::UnknownLocale.with(&[&name]));
+                    return 64;
+                }
+            },
+            _ => {
+                eprintln!("{}", Text::UnknownOption.with(&[&option]));
+                return 64;
+            }
+        }
+    }
+
+    if arguments.get(1).is_some_and(|command| command == "explain") {
+        return explain::explain(arguments.get(2).map(String::as_str));
+    }
+
+    let result = if arguments.len() == 1 {
         // run lox code from a file
-        lox.run_file("file.lox");
+        lox.run_file("file.lox")
+    } else if arguments.len() == 2 && arguments[1].ends_with(".lox") {
+        // run lox code from the given file
+        lox.run_file(&arguments[1])
     } else {
         // run lox code from a prompt
         let mut input = String::new();
@@ -32,14 +109,11 @@ fn main() {
         }
 
         println!("{}", input);
-        lox.run_prompt(input);
-    }
-}
+        lox.run_prompt(input)
+    };
 
-pub fn crash(line: usize, message: &str) -> ! {
-    let l = "[line ".blue();
-    let i = "] Error: ".blue();
-    let message = message.red();
-    panic!("{}{}{}{}", l, line, i, message);
-    //panic!("[line {}] Error: {}", line, message);
+    match result {
+        Ok(()) => 0,
+        Err(error) => report(&error),
+    }
 }
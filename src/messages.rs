@@ -0,0 +1,902 @@
+use std::{
+    fmt::Display,
+    sync::atomic::{AtomicU8, Ordering},
+};
+
+// every text Rox shows, in three languages
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Locale {
+    // the texts Rox always had, with the jokes
+    Playful,
+    // the same in Dutch, but fit for a classroom or a report
+    Neutral,
+    English,
+}
+impl Locale {
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "speels" => Some(Locale::Playful),
+            "neutraal" => Some(Locale::Neutral),
+            "engels" | "english" | "en" => Some(Locale::English),
+            _ => None,
+        }
+    }
+}
+
+// the interpreter runs on its own thread, so the language is shared by the whole process
+static LOCALE: AtomicU8 = AtomicU8::new(0);
+
+pub fn set_locale(locale: Locale) {
+    LOCALE.store(locale as u8, Ordering::Relaxed);
+}
+
+pub fn locale() -> Locale {
+    match LOCALE.load(Ordering::Relaxed) {
+        1 => Locale::Neutral,
+        2 => Locale::English,
+        _ => Locale::Playful,
+    }
+}
+
+// fills in the {} in order, or {0}, {1} when a value is used twice
+// any other '{' is just text, like in "Verwachtte '{'"
+fn fill(template: &str, args: &[&dyn Display]) -> String {
+    let mut out = String::new();
+    let mut next = 0;
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        out += &rest[..start];
+        let after = &rest[start + 1..];
+        let digits = after.len() - after.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+        if !after[digits..].starts_with('}') {
+            out.push('{');
+            rest = after;
+            continue;
+        }
+        let index = match after[..digits].parse() {
+            Ok(index) => index,
+            Err(_) => {
+                next += 1;
+                next - 1
+            }
+        };
+        if let Some(arg) = args.get(index) {
+            out += &arg.to_string();
+        }
+        rest = &after[digits + 1..];
+    }
+    out + rest
+}
+
+// a diagnostic with its text in the current language
+#[derive(Debug, Clone, PartialEq)]
+pub struct Message {
+    pub code: Code,
+    pub text: String,
+}
+impl From<Code> for Message {
+    fn from(code: Code) -> Self {
+        code.with(&[])
+    }
+}
+impl Display for Message {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text)
+    }
+}
+
+macro_rules! catalog {
+    ($name: ident { $($variant: ident = $number: literal => [$playful: expr, $neutral: expr, $english: expr $(,)?],)* }) => {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        #[repr(u16)]
+        pub enum $name {
+            $($variant = $number,)*
+        }
+        impl $name {
+            // only Code is looked up by its number, Text never is
+            #[allow(dead_code)]
+            pub const ALL: &'static [$name] = &[$($name::$variant,)*];
+
+            pub fn template(self) -> &'static str {
+                match (self, locale()) {
+                    $(
+                        ($name::$variant, Locale::Playful) => $playful,
+                        ($name::$variant, Locale::Neutral) => $neutral,
+                        ($name::$variant, Locale::English) => $english,
+                    )*
+                }
+            }
+        }
+    };
+}
+
+// the number of a code never changes, so it can be looked up; new codes get a new number
+// R00xx syntax, R01xx names and arguments, R02xx runtime, R03xx built-in functions, R04xx files
+// R05xx warnings
+catalog!(Code {
+    UnexpectedCharacter = 1 => [
+        "Onverwacht karakter, dat kan beter appelflap",
+        "Onverwacht teken.",
+        "Unexpected character.",
+    ],
+    UnterminatedString = 2 => [
+        "ongetermineerde reeks, appelflap",
+        "Deze tekst wordt niet afgesloten met '\"'.",
+        "Unterminated string.",
+    ],
+    MissingSemicolon = 3 => [
+        "Je bent een ';' vergeten druiloor",
+        "Hier ontbreekt een ';'.",
+        "Expected ';'.",
+    ],
+    MissingParen = 4 => [
+        "Je bent de ')' vergeten druiloor",
+        "Hier ontbreekt een ')'.",
+        "Expected ')'.",
+    ],
+    MissingBrace = 5 => [
+        "je bent een '}' vergeten druiloor",
+        "Hier ontbreekt een '}'.",
+        "Expected '}'.",
+    ],
+    MissingBracket = 6 => [
+        "Je bent de ']' vergeten druiloor",
+        "Hier ontbreekt een ']'.",
+        "Expected ']'.",
+    ],
+    UnclosedGroup = 7 => [
+        "Je bent de ')' vergeten (je mag niet meer op mijn kinderfeestje komen)",
+        "Hier ontbreekt de ')' na de expressie.",
+        "Expected ')' after the expression.",
+    ],
+    UnexpectedToken = 8 => [
+        "{} past hier niet oelewapper.",
+        "{} hoort hier niet.",
+        "Unexpected {}.",
+    ],
+    ExpectedPipe = 9 => [
+        "Onverwacht karakter, bedoelde je '|>'?",
+        "Onverwacht teken, bedoelde je '|>'?",
+        "Unexpected character, did you mean '|>'?",
+    ],
+    ImportNotAtTop = 10 => [
+        "'{}' kan alleen buiten blokken en processen, oelewapper.",
+        "'{}' kan alleen buiten blokken en processen.",
+        "'{}' can only be used outside of blocks and functions.",
+    ],
+    ExpectedModulePath = 11 => [
+        "Verwachtte een pad of de naam van een module na 'importeer'.",
+        "Verwachtte een pad of de naam van een module na 'importeer'.",
+        "Expected a path or the name of a module after 'importeer'.",
+    ],
+    InvalidPath = 12 => [
+        "'{}' is geen geldig pad.",
+        "'{}' is geen geldig pad.",
+        "'{}' is not a valid path.",
+    ],
+    ExpectedModuleName = 13 => [
+        "Je moet wel een naam aan de module geven",
+        "Verwachtte de naam van de module.",
+        "Expected a name for the module.",
+    ],
+    InvalidExport = 14 => [
+        "Je kan alleen 'laat', 'vast', 'proces', 'record', 'klas' en 'eigenschap' exporteren.",
+        "Je kan alleen 'laat', 'vast', 'proces', 'record', 'klas' en 'eigenschap' exporteren.",
+        "Only 'laat', 'vast', 'proces', 'record', 'klas' and 'eigenschap' can be exported.",
+    ],
+    ExpectedProcesName = 15 => [
+        "Je moet wel een naam aan het proces geven",
+        "Verwachtte de naam van het proces.",
+        "Expected a name for the function.",
+    ],
+    ExpectedParenAfterProcesName = 16 => [
+        "Verwachtte '(' na de naam van het proces.",
+        "Verwachtte '(' na de naam van het proces.",
+        "Expected '(' after the name of the function.",
+    ],
+    ExpectedProcesBody = 17 => [
+        "Verwachtte '{' voor de inhoud van het proces.",
+        "Verwachtte '{' voor de inhoud van het proces.",
+        "Expected '{' before the body of the function.",
+    ],
+    ExpectedParamName = 18 => [
+        "Je moet wel een naam aan de parameter geven",
+        "Verwachtte de naam van de parameter.",
+        "Expected a name for the parameter.",
+    ],
+    DuplicateParam = 19 => [
+        "Er is al een parameter die '{}' heet.",
+        "Er is al een parameter die '{}' heet.",
+        "There already is a parameter called '{}'.",
+    ],
+    DefaultNeeded = 20 => [
+        "'{}' heeft een standaardwaarde nodig, omdat de parameters ervoor er ook een hebben.",
+        "'{}' heeft een standaardwaarde nodig, omdat de parameters ervoor er ook een hebben.",
+        "'{}' needs a default value, because the parameters before it have one.",
+    ],
+    ParamAfterRest = 21 => [
+        "Na een '...' parameter kunnen geen parameters meer komen.",
+        "Na een '...' parameter kunnen geen parameters meer komen.",
+        "No parameters can come after a '...' parameter.",
+    ],
+    ExpectedParenAfterProces = 22 => [
+        "Verwachtte '(' na 'proces'.",
+        "Verwachtte '(' na 'proces'.",
+        "Expected '(' after 'proces'.",
+    ],
+    ExpectedArrow = 23 => [
+        "Verwachtte '=>' na de parameters.",
+        "Verwachtte '=>' na de parameters.",
+        "Expected '=>' after the parameters.",
+    ],
+    ExpectedRecordName = 24 => [
+        "Je moet wel een naam aan het record geven",
+        "Verwachtte de naam van het record.",
+        "Expected a name for the record.",
+    ],
+    ExpectedParenAfterRecordName = 25 => [
+        "Verwachtte '(' na de naam van het record.",
+        "Verwachtte '(' na de naam van het record.",
+        "Expected '(' after the name of the record.",
+    ],
+    ExpectedFieldName = 26 => [
+        "Je moet wel een naam aan het veld geven",
+        "Verwachtte de naam van een veld.",
+        "Expected the name of a field.",
+    ],
+    DuplicateField = 27 => [
+        "Het veld '{}' staat er al in.",
+        "Het veld '{}' staat er al in.",
+        "The field '{}' is already there.",
+    ],
+    ExpectedClassName = 28 => [
+        "Je moet wel een naam aan de klas geven",
+        "Verwachtte de naam van de klas.",
+        "Expected a name for the class.",
+    ],
+    ExpectedTraitName = 29 => [
+        "Je moet wel een naam aan de eigenschap geven",
+        "Verwachtte de naam van een eigenschap.",
+        "Expected the name of a trait.",
+    ],
+    ExpectedClassBody = 30 => [
+        "Verwachtte '{' voor de inhoud van de klas.",
+        "Verwachtte '{' voor de inhoud van de klas.",
+        "Expected '{' before the body of the class.",
+    ],
+    ExpectedParenAfterMethodName = 31 => [
+        "Verwachtte '(' na de naam van de methode.",
+        "Verwachtte '(' na de naam van de methode.",
+        "Expected '(' after the name of the method.",
+    ],
+    ExpectedTraitBody = 32 => [
+        "Verwachtte '{' voor de inhoud van de eigenschap.",
+        "Verwachtte '{' voor de inhoud van de eigenschap.",
+        "Expected '{' before the body of the trait.",
+    ],
+    DuplicateMethod = 33 => [
+        "Er is al een methode die '{}' heet.",
+        "Er is al een methode die '{}' heet.",
+        "There already is a method called '{}'.",
+    ],
+    ExpectedMethodName = 34 => [
+        "Verwachtte de naam van een methode.",
+        "Verwachtte de naam van een methode.",
+        "Expected the name of a method.",
+    ],
+    PatternNeedsValue = 35 => [
+        "Een patroon moet meteen een waarde krijgen, met '='.",
+        "Een patroon moet meteen een waarde krijgen, met '='.",
+        "A pattern needs a value right away, with '='.",
+    ],
+    ExpectedVariableName = 36 => [
+        "Je moet wel een naam aan de variabele geven",
+        "Verwachtte de naam van de variabele.",
+        "Expected a name for the variable.",
+    ],
+    ExpectedConstName = 37 => [
+        "Je moet wel een naam aan de constante geven",
+        "Verwachtte de naam van de constante.",
+        "Expected a name for the constant.",
+    ],
+    ConstNeedsValue = 38 => [
+        "Een constante moet meteen een waarde krijgen, met '='.",
+        "Een constante moet meteen een waarde krijgen, met '='.",
+        "A constant needs a value right away, with '='.",
+    ],
+    ExpectedVan = 39 => [
+        "Verwachtte 'van'.",
+        "Verwachtte 'van'.",
+        "Expected 'van'.",
+    ],
+    ExpectedTot = 40 => [
+        "Verwachtte 'tot'.",
+        "Verwachtte 'tot'.",
+        "Expected 'tot'.",
+    ],
+    ExpectedVanOrIn = 41 => [
+        "Verwachtte 'van' of 'in'.",
+        "Verwachtte 'van' of 'in'.",
+        "Expected 'van' or 'in'.",
+    ],
+    ReturnOutsideProces = 42 => [
+        "'retour' kan alleen binnen een proces, oelewapper.",
+        "'retour' kan alleen binnen een proces.",
+        "'retour' can only be used inside a function.",
+    ],
+    YieldOutsideProces = 43 => [
+        "'lever' kan alleen binnen een proces, oelewapper.",
+        "'lever' kan alleen binnen een proces.",
+        "'lever' can only be used inside a function.",
+    ],
+    AssignOnlyVariables = 44 => [
+        "Je kan alleen aan variabelen toewijzen.",
+        "Je kan alleen aan variabelen toewijzen.",
+        "Only variables can be assigned to.",
+    ],
+    ExpectedEqualsAfterNames = 45 => [
+        "Verwachtte '=' na de namen.",
+        "Verwachtte '=' na de namen.",
+        "Expected '=' after the names.",
+    ],
+    AssignCountMismatch = 46 => [
+        "Links staan {} namen, maar rechts staan {} waarden.",
+        "Links staan {} namen, maar rechts staan {} waarden.",
+        "There are {} names on the left, but {} values on the right.",
+    ],
+    InvalidAssignTarget = 47 => [
+        "dit kan je niet assignen.",
+        "Hier kan geen waarde aan worden toegewezen.",
+        "Invalid assignment target.",
+    ],
+    ExpectedNameAfterDot = 48 => [
+        "Verwachtte een naam na de '.'.",
+        "Verwachtte een naam na de '.'.",
+        "Expected a name after '.'.",
+    ],
+    PositionalAfterNamed = 49 => [
+        "Na een benoemd argument kunnen alleen nog benoemde argumenten komen.",
+        "Na een benoemd argument kunnen alleen nog benoemde argumenten komen.",
+        "Only named arguments can come after a named argument.",
+    ],
+    ThisOutsideClass = 50 => [
+        "'dit' kan alleen binnen de methoden van een klas, oelewapper.",
+        "'dit' kan alleen binnen de methoden van een klas.",
+        "'dit' can only be used inside the methods of a class.",
+    ],
+    YieldPosition = 51 => [
+        "'lever' kan alleen als losse opdracht, of als waarde van 'laat' of '='.",
+        "'lever' kan alleen als losse opdracht, of als waarde van 'laat' of '='.",
+        "'lever' can only be a statement of its own, or the value of 'laat' or '='.",
+    ],
+    ExpectedColonAfterKey = 52 => [
+        "Verwachtte ':' na de sleutel.",
+        "Verwachtte ':' na de sleutel.",
+        "Expected ':' after the key.",
+    ],
+    ExpectedInAfterName = 53 => [
+        "Verwachtte 'in' na de naam.",
+        "Verwachtte 'in' na de naam.",
+        "Expected 'in' after the name.",
+    ],
+    UnknownPragma = 54 => [
+        "'{}' snap ik niet, probeer '// rox: sta_toe(R0501)' of '// rox: verbied(R0501)'.",
+        "'{}' is geen pragma, gebruik '// rox: sta_toe(R0501)' of '// rox: verbied(R0501)'.",
+        "'{}' is not a pragma, use '// rox: sta_toe(R0501)' or '// rox: verbied(R0501)'.",
+    ],
+    NotAWarning = 55 => [
+        "'{}' is geen waarschuwing, dus die kan je niet toestaan of verbieden.",
+        "'{}' is geen waarschuwing, alleen waarschuwingen kunnen worden toegestaan of verboden.",
+        "'{}' is not a warning, only warnings can be allowed or denied.",
+    ],
+
+    ConstAssign = 101 => [
+        "'{}' is vast en kan niet worden veranderd.",
+        "'{}' is vast en kan niet worden veranderd.",
+        "'{}' is a constant and can't be changed.",
+    ],
+    UnknownVariable = 102 => [
+        "'{}' is een onbekende variabele.",
+        "'{}' is een onbekende variabele.",
+        "Unknown variable '{}'.",
+    ],
+    ModuleMissingExport = 103 => [
+        "Module '{}' exporteert geen '{}'.",
+        "Module '{}' exporteert geen '{}'.",
+        "Module '{}' doesn't export '{}'.",
+    ],
+    RecordMissingField = 104 => [
+        "Record '{}' heeft geen veld '{}'.",
+        "Record '{}' heeft geen veld '{}'.",
+        "Record '{}' has no field '{}'.",
+    ],
+    NoFieldOrMethod = 105 => [
+        "Een {} heeft geen veld of methode '{}'.",
+        "Een {} heeft geen veld of methode '{}'.",
+        "A {} has no field or method '{}'.",
+    ],
+    NoProperty = 106 => [
+        "{} heeft geen '{}', oelewapper.",
+        "{} heeft geen '{}'.",
+        "{} has no '{}'.",
+    ],
+    KeyMissing = 107 => [
+        "De sleutel '{}' bestaat niet.",
+        "De sleutel '{}' bestaat niet.",
+        "The key '{}' doesn't exist.",
+    ],
+    UnknownParameter = 108 => [
+        "'{}' heeft geen parameter die '{}' heet.",
+        "'{}' heeft geen parameter die '{}' heet.",
+        "'{}' has no parameter called '{}'.",
+    ],
+    DuplicateArgument = 109 => [
+        "'{}' kreeg al een waarde voor '{}'.",
+        "'{}' kreeg al een waarde voor '{}'.",
+        "'{}' already got a value for '{}'.",
+    ],
+    MissingArgument = 110 => [
+        "'{}' mist een waarde voor '{}'.",
+        "'{}' mist een waarde voor '{}'.",
+        "'{}' is missing a value for '{}'.",
+    ],
+    ArityMismatch = 111 => [
+        "'{}' verwacht {} argumenten maar kreeg er {}.",
+        "'{}' verwacht {} argumenten maar kreeg er {}.",
+        "'{}' expects {} arguments but got {}.",
+    ],
+    NativeNamedArgs = 112 => [
+        "'{}' is ingebouwd en kent geen benoemde argumenten.",
+        "'{}' is ingebouwd en kent geen benoemde argumenten.",
+        "'{}' is built in and has no named arguments.",
+    ],
+    RecursionTooDeep = 113 => [
+        "Recursie te diep, er zijn al {} processen aangeroepen die nog niet klaar zijn.",
+        "Recursie te diep, er zijn al {} processen aangeroepen die nog niet klaar zijn.",
+        "Recursion too deep, {} functions were called that haven't returned yet.",
+    ],
+    ReadInOwnInitializer = 114 => [
+        "'{}' bestaat nog niet zolang zijn waarde wordt uitgerekend, kip of ei?",
+        "'{}' wordt gelezen in zijn eigen beginwaarde.",
+        "'{}' is read in its own initializer.",
+    ],
+
+    PatternExpectsList = 201 => [
+        "Het patroon verwacht een lijst, maar kreeg {}.",
+        "Het patroon verwacht een lijst, maar kreeg {}.",
+        "The pattern expects a list, but got {}.",
+    ],
+    PatternLength = 202 => [
+        "Het patroon verwacht {} waarden, maar de lijst heeft er {}.",
+        "Het patroon verwacht {} waarden, maar de lijst heeft er {}.",
+        "The pattern expects {} values, but the list has {}.",
+    ],
+    MultiAssignNotList = 203 => [
+        "Rechts staat geen lijst maar {}.",
+        "Rechts staat geen lijst maar {}.",
+        "Expected a list on the right, but got {}.",
+    ],
+    MultiAssignLength = 204 => [
+        "Links staan {} namen, maar de lijst heeft {} waarden.",
+        "Links staan {} namen, maar de lijst heeft {} waarden.",
+        "There are {} names on the left, but the list has {} values.",
+    ],
+    NotATrait = 205 => [
+        "{} is geen eigenschap, oelewapper.",
+        "{} is geen eigenschap.",
+        "{} is not a trait.",
+    ],
+    MissingTraitMethod = 206 => [
+        "Klas '{}' implementeert '{}', maar mist de methode '{}'.",
+        "Klas '{}' implementeert '{}', maar mist de methode '{}'.",
+        "Class '{}' implements '{}', but is missing the method '{}'.",
+    ],
+    NegateNonNumber = 207 => [
+        "Min kan alleen worden gebruikt voor nummers, kaaskop",
+        "Min kan alleen worden gebruikt voor nummers.",
+        "Unary minus can only be used on numbers.",
+    ],
+    NotNonBool = 208 => [
+        "Uitroepteken kan alleen worden gebruikt op waarheidswaardes, kaaskop",
+        "Een uitroepteken kan alleen worden gebruikt op wellus of nietus.",
+        "'!' can only be used on wellus or nietus.",
+    ],
+    ArithmeticNonNumber = 209 => [
+        "{} kan alleen worden gebruikt op nummers, kaaskop",
+        "{} kan alleen worden gebruikt op nummers.",
+        "{} can only be used on numbers.",
+    ],
+    PlusTypes = 210 => [
+        "Plus kan alleen worden gebruikt op nummers en strings, kaaskop.",
+        "Plus kan alleen worden gebruikt op nummers en teksten.",
+        "'+' can only be used on numbers and strings.",
+    ],
+    CaretTypes = 211 => [
+        "Caret kan alleen worden gebruikt op nummers, kaaskop.",
+        "'^' kan alleen worden gebruikt op nummers.",
+        "'^' can only be used on numbers.",
+    ],
+    IsNeedsType = 212 => [
+        "Rechts van '{}' moet een klas, eigenschap of record staan, niet {}.",
+        "Rechts van '{}' moet een klas, eigenschap of record staan, niet {}.",
+        "The right side of '{}' has to be a class, trait or record, not {}.",
+    ],
+    OperatorObjectLeft = 213 => [
+        "Bij {} {} {} moet het object links staan, want alleen een object heeft de methode '{}'.",
+        "Bij {} {} {} moet het object links staan, want alleen een object heeft de methode '{}'.",
+        "In {} {} {} the object has to be on the left, because only an object has the method '{}'.",
+    ],
+    MissingOperatorMethod = 214 => [
+        "Klas '{0}' heeft geen methode '{1}', dus {2} werkt niet op een {0} object.",
+        "Klas '{0}' heeft geen methode '{1}', dus {2} werkt niet op een {0} object.",
+        "Class '{0}' has no method '{1}', so {2} doesn't work on a {0} object.",
+    ],
+    MustReturnBool = 215 => [
+        "'{}' van klas '{}' moet wellus of nietus teruggeven, niet {}.",
+        "'{}' van klas '{}' moet wellus of nietus teruggeven, niet {}.",
+        "'{}' of class '{}' has to return wellus or nietus, not {}.",
+    ],
+    MustReturnText = 216 => [
+        "'als_tekst' van klas '{}' moet tekst teruggeven, niet {}.",
+        "'als_tekst' van klas '{}' moet tekst teruggeven, niet {}.",
+        "'als_tekst' of class '{}' has to return a string, not {}.",
+    ],
+    LogicNonBool = 217 => [
+        "'{}' kan alleen worden gebruikt op waardigheids waarden, kaaskop.",
+        "'{}' kan alleen worden gebruikt op wellus of nietus.",
+        "'{}' can only be used on wellus or nietus.",
+    ],
+    SpreadNonList = 218 => [
+        "Alleen lijsten, tupels, mappen en reeksen kan je uitspreiden met '...'.",
+        "Alleen lijsten, tupels, mappen en teksten kan je uitspreiden met '...'.",
+        "Only lists, tuples, maps and strings can be spread with '...'.",
+    ],
+    NotCallable = 219 => [
+        "{} is geen proces, je kan het niet aanroepen.",
+        "{} is geen proces en kan niet worden aangeroepen.",
+        "{} is not a function and can't be called.",
+    ],
+    GeneratorRunning = 220 => [
+        "De generator '{}' loopt al.",
+        "De generator '{}' loopt al.",
+        "The generator '{}' is already running.",
+    ],
+    SetFieldOnNonObject = 221 => [
+        "Alleen objecten hebben velden die je kan veranderen, {} niet.",
+        "Alleen objecten hebben velden die je kan veranderen, {} niet.",
+        "Only objects have fields that can be changed, {} doesn't.",
+    ],
+    ConditionNotBool = 222 => [
+        "'als' verwacht wellus of nietus, maar kreeg {}.",
+        "'als' verwacht wellus of nietus, maar kreeg {}.",
+        "'als' expects wellus or nietus, but got {}.",
+    ],
+    RangeNonNumber = 223 => [
+        "'van' en 'tot' kunnen alleen worden gebruikt met nummers.",
+        "'van' en 'tot' kunnen alleen worden gebruikt met nummers.",
+        "'van' and 'tot' can only be used with numbers.",
+    ],
+    NotIndexable = 224 => [
+        "{} is geen lijst of tekst, oelewapper.",
+        "{} is geen lijst of tekst.",
+        "{} is not a list or string.",
+    ],
+    TupleImmutable = 225 => [
+        "Een tupel kan niet worden veranderd, maak een nieuwe.",
+        "Een tupel kan niet worden veranderd, maak een nieuwe.",
+        "A tuple can't be changed, make a new one.",
+    ],
+    NotAList = 226 => [
+        "{} is geen lijst, oelewapper.",
+        "{} is geen lijst.",
+        "{} is not a list.",
+    ],
+    UnhandledFout = 227 => [
+        "Fout({}) is niet afgehandeld.",
+        "Fout({}) is niet afgehandeld.",
+        "Fout({}) was not handled.",
+    ],
+    QuestionOnNonResult = 228 => [
+        "'?' kan alleen worden gebruikt op Ok of Fout, niet op {}.",
+        "'?' kan alleen worden gebruikt op Ok of Fout, niet op {}.",
+        "'?' can only be used on Ok or Fout, not on {}.",
+    ],
+    NotIterable = 229 => [
+        "Je kan niet door {} heen lopen.",
+        "Je kan niet door {} heen lopen.",
+        "{} can't be looped over.",
+    ],
+    IndexOutOfRange = 230 => [
+        "Index {} valt buiten de lijst met lengte {}.",
+        "Index {} valt buiten de lijst met lengte {}.",
+        "Index {} is out of range for a list of length {}.",
+    ],
+    InvalidIndex = 231 => [
+        "{} is geen geldige index.",
+        "{} is geen geldige index.",
+        "{} is not a valid index.",
+    ],
+
+    ExpectedResult = 301 => [
+        "{} verwacht een Ok of Fout, maar kreeg {}.",
+        "{} verwacht een Ok of Fout, maar kreeg {}.",
+        "{} expects an Ok or Fout, but got {}.",
+    ],
+    UnwrapFout = 302 => [
+        "uitpakken van een Fout: {}",
+        "uitpakken van een Fout: {}",
+        "uitpakken of a Fout: {}",
+    ],
+    NoLength = 303 => [
+        "{} heeft geen lengte.",
+        "{} heeft geen lengte.",
+        "{} has no length.",
+    ],
+    NotANumber = 304 => [
+        "'{}' is geen getal.",
+        "'{}' is geen getal.",
+        "'{}' is not a number.",
+    ],
+    ExpectedPath = 305 => [
+        "{} verwacht een pad, maar kreeg {}.",
+        "{} verwacht een pad, maar kreeg {}.",
+        "{} expects a path, but got {}.",
+    ],
+    RecursionLimitInvalid = 306 => [
+        "recursie_limiet verwacht een heel getal groter dan 0, maar kreeg {}.",
+        "recursie_limiet verwacht een heel getal groter dan 0, maar kreeg {}.",
+        "recursie_limiet expects a whole number above 0, but got {}.",
+    ],
+    ExpectedGenerator = 307 => [
+        "{} verwacht een generator, maar kreeg {}.",
+        "{} verwacht een generator, maar kreeg {}.",
+        "{} expects a generator, but got {}.",
+    ],
+    ExpectedProces = 308 => [
+        "{} verwacht een proces, maar kreeg {}.",
+        "{} verwacht een proces, maar kreeg {}.",
+        "{} expects a function, but got {}.",
+    ],
+    WaitInsideTask = 309 => [
+        "Binnen een taak wacht je met 'lever {}', niet met wacht.",
+        "Binnen een taak wacht je met 'lever {}', niet met wacht.",
+        "Inside a task you wait with 'lever {}', not with wacht.",
+    ],
+    WaitInvalid = 310 => [
+        "wacht verwacht een taak, slaap of ontvang, maar kreeg {}.",
+        "wacht verwacht een taak, slaap of ontvang, maar kreeg {}.",
+        "wacht expects a task, slaap or ontvang, but got {}.",
+    ],
+    SleepInvalid = 311 => [
+        "slaap kan niet {} milliseconden duren.",
+        "slaap kan niet {} milliseconden duren.",
+        "slaap can't take {} milliseconds.",
+    ],
+    ExpectedChannel = 312 => [
+        "{} verwacht een kanaal, maar kreeg {}.",
+        "{} verwacht een kanaal, maar kreeg {}.",
+        "{} expects a channel, but got {}.",
+    ],
+    ExpectedNumber = 313 => [
+        "{} verwacht een nummer, maar kreeg {}.",
+        "{} verwacht een nummer, maar kreeg {}.",
+        "{} expects a number, but got {}.",
+    ],
+    NegativeRoot = 314 => [
+        "Je kan geen wortel trekken uit {}.",
+        "Je kan geen wortel trekken uit {}.",
+        "Can't take the square root of {}.",
+    ],
+    ExpectedList = 315 => [
+        "{} verwacht een lijst, maar kreeg {}.",
+        "{} verwacht een lijst, maar kreeg {}.",
+        "{} expects a list, but got {}.",
+    ],
+    CallbackMustReturnBool = 316 => [
+        "Het proces van {} moet wellus of nietus geven, niet {}.",
+        "Het proces van {} moet wellus of nietus geven, niet {}.",
+        "The function given to {} has to return wellus or nietus, not {}.",
+    ],
+    FoldNeedsStart = 317 => [
+        "vouw heeft een beginwaarde nodig voor een lege lijst.",
+        "vouw heeft een beginwaarde nodig voor een lege lijst.",
+        "vouw needs a starting value for an empty list.",
+    ],
+    SortCallbackInvalid = 318 => [
+        "Het proces van sorteer moet wellus, nietus of een nummer geven, niet {}.",
+        "Het proces van sorteer moet wellus, nietus of een nummer geven, niet {}.",
+        "The function given to sorteer has to return wellus, nietus or a number, not {}.",
+    ],
+    CannotCompare = 319 => [
+        "{} kan alleen nummers met nummers en teksten met teksten vergelijken, niet {} met {}.",
+        "{} kan alleen nummers met nummers en teksten met teksten vergelijken, niet {} met {}.",
+        "{} can only compare numbers with numbers and strings with strings, not {} with {}.",
+    ],
+    EmptyList = 320 => [
+        "{} van een lege lijst bestaat niet.",
+        "{} van een lege lijst bestaat niet.",
+        "{} of an empty list doesn't exist.",
+    ],
+    Deadlock = 321 => [
+        "Alle taken wachten op elkaar, dus dit gaat nooit meer verder.",
+        "Alle taken wachten op elkaar, dus dit gaat nooit meer verder.",
+        "All tasks are waiting on each other, so this will never continue.",
+    ],
+
+    FileNotFound = 401 => [
+        "{} is niet gevonden. het moet in dezelfde directory als de binary of Cargo.toml zitten.",
+        "{} is niet gevonden, het moet in dezelfde map als het programma of Cargo.toml staan.",
+        "{} was not found, it has to be in the same directory as the binary or Cargo.toml.",
+    ],
+    ModuleNotFound = 402 => [
+        "'{}' is niet gevonden.",
+        "'{}' is niet gevonden.",
+        "'{}' was not found.",
+    ],
+    ImportCycle = 403 => [
+        "Deze bestanden importeren elkaar: {}",
+        "Deze bestanden importeren elkaar: {}",
+        "These files import each other: {}",
+    ],
+    FileUnreadable = 404 => [
+        "'{}' kon niet worden gelezen: {}",
+        "'{}' kon niet worden gelezen: {}",
+        "'{}' could not be read: {}",
+    ],
+
+    UnusedVariable = 501 => [
+        "'{}' wordt nooit gebruikt, zonde van de moeite.",
+        "'{}' wordt nooit gebruikt.",
+        "'{}' is never used.",
+    ],
+    ShadowedLoopVariable = 502 => [
+        "Deze '{}' verstopt de lusvariabele, die is hierna onvindbaar.",
+        "'{}' verbergt de lusvariabele van 'voor'.",
+        "'{}' hides the loop variable of 'voor'.",
+    ],
+    UnreachableAfterReturn = 503 => [
+        "Na deze 'retour' komt het nooit meer, de rest van het blok is voor niks.",
+        "De code na deze 'retour' wordt nooit uitgevoerd.",
+        "The code after this 'retour' is never run.",
+    ],
+    AlwaysFalseComparison = 504 => [
+        "Dit vergelijkt {} met {}, dat is altijd nietus, sukkel.",
+        "Dit vergelijkt {} met {}, dat is altijd nietus.",
+        "This compares {} with {}, which is always nietus.",
+    ],
+    EmptyLoopBody = 505 => [
+        "Deze lus doet helemaal niks, de inhoud is leeg.",
+        "Deze lus doet niks, de inhoud is leeg.",
+        "This loop does nothing, its body is empty.",
+    ],
+});
+
+impl Code {
+    pub fn with(self, args: &[&dyn Display]) -> Message {
+        Message {
+            code: self,
+            text: fill(self.template(), args),
+        }
+    }
+
+    // like R0003, the same in every language
+    pub fn id(self) -> String {
+        format!("R{:04}", self as u16)
+    }
+
+    // takes R0003, r0003 or just 3
+    pub fn from_id(id: &str) -> Option<Code> {
+        let number: u16 = id.trim_start_matches(['R', 'r']).parse().ok()?;
+        Code::ALL
+            .iter()
+            .copied()
+            .find(|code| *code as u16 == number)
+    }
+
+    // the message without the values that are filled in, for lists and explanations
+    pub fn title(self) -> String {
+        let dots: [&dyn Display; 4] = [&"…", &"…", &"…", &"…"];
+        fill(self.template(), &dots)
+    }
+}
+
+// texts that are not an error themselves, like labels and the steps of a trace
+catalog!(Text {
+    ErrorHeader = 1 => ["Error[{}]:", "Fout[{}]:", "Error[{}]:"],
+    Help = 2 => ["= hulp:", "= hulp:", "= help:"],
+    ErrorCount = 3 => ["{} fouten gevonden.", "{} fouten gevonden.", "{} errors found."],
+    TraceHeader = 4 => [
+        "Spoor (de laatste stap staat onderaan):",
+        "Spoor (de laatste stap staat onderaan):",
+        "Traceback (most recent step last):",
+    ],
+    Repeated = 5 => [
+        "... nog {} keer hetzelfde",
+        "... nog {} keer hetzelfde",
+        "... the same {} more times",
+    ],
+    DidYouMean = 6 => ["bedoelde je {}?", "bedoelde je {}?", "did you mean {}?"],
+    Or = 7 => [" of ", " of ", " or "],
+    BlockStartsHere = 8 => ["dit blok begint hier", "dit blok begint hier", "this block starts here"],
+    DeclaredConstHere = 9 => ["hier vast gemaakt", "hier vast gemaakt", "made constant here"],
+    WhileRound = 10 => ["terwijl, ronde {}", "terwijl, ronde {}", "terwijl, round {}"],
+    ForInRound = 11 => ["voor, ronde {}", "voor, ronde {}", "voor, round {}"],
+    ForValue = 12 => ["voor, {} = {}", "voor, {} = {}", "voor, {} = {}"],
+    ProcesFrame = 13 => ["proces {}", "proces {}", "function {}"],
+    NativeFrame = 14 => ["ingebouwd proces {}", "ingebouwd proces {}", "built-in function {}"],
+    GeneratorFrame = 15 => ["generator {}", "generator {}", "generator {}"],
+    ImportFrame = 16 => ["importeer {}", "importeer {}", "import {}"],
+    AtLeast = 17 => ["minstens {}", "minstens {}", "at least {}"],
+    Between = 18 => ["{} tot {}", "{} tot {}", "{} to {}"],
+    Input = 19 => ["<invoer>", "<invoer>", "<input>"],
+    InvalidMaxErrors = 20 => [
+        "--max-fouten moet een getal boven 0 zijn, niet '{}'.",
+        "--max-fouten moet een getal boven 0 zijn, niet '{}'.",
+        "--max-fouten has to be a number above 0, not '{}'.",
+    ],
+    UnknownOption = 21 => ["Onbekende optie '{}'.", "Onbekende optie '{}'.", "Unknown option '{}'."],
+    UnknownLocale = 22 => [
+        "'{}' is geen taal, kies speels, neutraal of engels.",
+        "'{}' is geen taal, kies speels, neutraal of engels.",
+        "'{}' is not a language, choose speels, neutraal or engels.",
+    ],
+    ExplainHint = 23 => [
+        "Meer uitleg over deze fout: {} explain {}",
+        "Meer uitleg over deze fout: {} explain {}",
+        "More about this error: {} explain {}",
+    ],
+    UnknownCode = 24 => [
+        "'{}' is geen code van Rox, zoiets als R0003 wel.",
+        "'{}' is geen code van Rox, zoiets als R0003 wel.",
+        "'{}' is not a Rox code, something like R0003 is.",
+    ],
+    Wrong = 25 => ["Zo gaat het mis:", "Zo gaat het mis:", "Wrong:"],
+    Right = 26 => ["Zo gaat het goed:", "Zo gaat het goed:", "Correct:"],
+    WarningHeader = 27 => ["Waarschuwing[{}]:", "Waarschuwing[{}]:", "Warning[{}]:"],
+    WarningCount = 28 => [
+        "{} waarschuwingen gevonden.",
+        "{} waarschuwingen gevonden.",
+        "{} warnings found.",
+    ],
+    DeniedByFlag = 29 => [
+        "deze waarschuwing is een fout door --deny-warnings",
+        "deze waarschuwing is een fout door --deny-warnings",
+        "this warning is an error because of --deny-warnings",
+    ],
+    DeniedHere = 30 => ["hier verboden", "hier verboden", "denied here"],
+    LoopVariableHere = 31 => ["de lusvariabele", "de lusvariabele", "the loop variable"],
+    UnusedHelp = 32 => [
+        "begin de naam met '_' als dat de bedoeling is",
+        "begin de naam met '_' als dat de bedoeling is",
+        "start the name with '_' if that is intended",
+    ],
+    Number = 33 => ["een getal", "een getal", "a number"],
+    String = 34 => ["een tekst", "een tekst", "a string"],
+    Bool = 35 => ["wellus of nietus", "wellus of nietus", "wellus or nietus"],
+    Nil = 36 => ["niks", "niks", "niks"],
+    List = 37 => ["een lijst", "een lijst", "a list"],
+    Tuple = 38 => ["een tupel", "een tupel", "a tuple"],
+    Map = 39 => ["een map", "een map", "a map"],
+    Proces = 40 => ["een proces", "een proces", "a function"],
+    DeclaredLaterHere = 41 => [
+        "pas hier gemaakt",
+        "pas hier gemaakt",
+        "only declared here",
+    ],
+    NativeValue = 42 => [
+        "<ingebouwd proces {}>",
+        "<ingebouwd proces {}>",
+        "<built-in function {}>",
+    ],
+});
+
+impl Text {
+    pub fn with(self, args: &[&dyn Display]) -> String {
+        fill(self.template(), args)
+    }
+}
+impl Display for Text {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.template())
+    }
+}
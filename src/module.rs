@@ -0,0 +1,14 @@
+use std::collections::HashMap;
+
+use crate::value::Value;
+
+#[derive(Debug)]
+pub struct Module {
+    pub name: String,
+    pub exports: HashMap<String, Value>,
+}
+impl Module {
+    pub fn new(name: String, exports: HashMap<String, Value>) -> Self {
+        Self { name, exports }
+    }
+}
@@ -0,0 +1,518 @@
+use std::{cell::RefCell, collections::HashMap, f64::consts::PI, fs, rc::Rc};
+
+use crate::{
+    function::NativeFn,
+    generator::Generator,
+    interpreter::Interpreter,
+    messages::{Code, Message},
+    module::Module,
+    scheduler::{self, Blocked, Channel, Task, Wait},
+    value::Value,
+};
+
+pub fn globals() -> Vec<NativeFn> {
+    vec![
+        NativeFn::new("Ok", 1, ok),
+        NativeFn::new("Fout", 1, fout),
+        NativeFn::new("is_ok", 1, is_ok),
+        NativeFn::new("is_fout", 1, is_fout),
+        NativeFn::new("uitpakken", 1, uitpakken),
+        NativeFn::new("toon", 1, toon),
+        NativeFn::new("lengte", 1, lengte),
+        NativeFn::new("voeg_toe", 2, voeg_toe),
+        NativeFn::new("kaart_toe", 2, kaart_toe),
+        NativeFn::new("map", 2, kaart_toe),
+        NativeFn::new("filter", 2, filter),
+        NativeFn::with_optional("vouw", 2, 3, vouw),
+        NativeFn::with_optional("reduce", 2, 3, vouw),
+        NativeFn::with_optional("sorteer", 1, 2, sorteer),
+        NativeFn::new("zip", 2, zip),
+        NativeFn::new("enumereer", 1, enumereer),
+        NativeFn::new("som", 1, som),
+        NativeFn::new("min", 1, min),
+        NativeFn::new("max", 1, max),
+        NativeFn::new("omgekeerd", 1, omgekeerd),
+        NativeFn::new("getal", 1, getal),
+        NativeFn::new("probeer_getal", 1, probeer_getal),
+        NativeFn::new("lees_bestand", 1, lees_bestand),
+        NativeFn::new("probeer_lees_bestand", 1, probeer_lees_bestand),
+        NativeFn::new("probeer", 1, probeer),
+        NativeFn::new("laatste_spoor", 0, laatste_spoor),
+        NativeFn::with_optional("recursie_limiet", 0, 1, recursie_limiet),
+        NativeFn::new("volgende", 1, volgende),
+        NativeFn::new("stuur", 2, stuur),
+        NativeFn::new("klaar", 1, klaar),
+        NativeFn::with_optional("taak", 1, usize::MAX, taak),
+        NativeFn::new("wacht", 1, wacht),
+        NativeFn::new("slaap", 1, slaap),
+        NativeFn::new("kanaal", 0, kanaal),
+        NativeFn::new("verstuur", 2, verstuur),
+        NativeFn::new("ontvang", 1, ontvang),
+        NativeFn::new("nu", 0, nu),
+        NativeFn::new("virtuele_klok", 0, virtuele_klok),
+    ]
+}
+
+// modules that can be imported by name, without a file
+pub fn module(name: &str) -> Option<Module> {
+    let natives = match name {
+        "wiskunde" => vec![
+            NativeFn::new("wortel", 1, wortel),
+            NativeFn::new("abs", 1, abs),
+            NativeFn::new("afronden", 1, afronden),
+            NativeFn::new("vloer", 1, vloer),
+            NativeFn::new("plafond", 1, plafond),
+            NativeFn::new("sin", 1, sin),
+            NativeFn::new("cos", 1, cos),
+        ],
+        _ => return None,
+    };
+
+    let mut exports = HashMap::new();
+    for native in natives {
+        exports.insert(native.name.to_string(), Value::Native(native));
+    }
+    if name == "wiskunde" {
+        exports.insert("pi".to_string(), Value::Num(PI));
+    }
+    Some(Module::new(name.to_string(), exports))
+}
+
+// turns a Rust result into a Rox result, so scripts can decide for themselves what to do with an error
+fn to_result(result: Result<Value, Message>) -> Value {
+    match result {
+        Ok(value) => Value::Ok(Box::new(value)),
+        Err(msg) => Value::Fout(Box::new(Value::Str(msg.text))),
+    }
+}
+
+fn ok(_: &mut Interpreter, args: &[Value]) -> Result<Value, Message> {
+    Ok(Value::Ok(Box::new(args[0].clone())))
+}
+
+fn fout(_: &mut Interpreter, args: &[Value]) -> Result<Value, Message> {
+    Ok(Value::Fout(Box::new(args[0].clone())))
+}
+
+fn is_ok(_: &mut Interpreter, args: &[Value]) -> Result<Value, Message> {
+    match &args[0] {
+        Value::Ok(_) => Ok(Value::True),
+        Value::Fout(_) => Ok(Value::False),
+        value => Err(Code::ExpectedResult.with(&[&"is_ok", &value])),
+    }
+}
+
+fn is_fout(_: &mut Interpreter, args: &[Value]) -> Result<Value, Message> {
+    match &args[0] {
+        Value::Ok(_) => Ok(Value::False),
+        Value::Fout(_) => Ok(Value::True),
+        value => Err(Code::ExpectedResult.with(&[&"is_fout", &value])),
+    }
+}
+
+fn uitpakken(_: &mut Interpreter, args: &[Value]) -> Result<Value, Message> {
+    match &args[0] {
+        Value::Ok(value) => Ok(*value.clone()),
+        Value::Fout(err) => Err(Code::UnwrapFout.with(&[&err])),
+        value => Err(Code::ExpectedResult.with(&[&"uitpakken", &value])),
+    }
+}
+
+// prints a value and gives it back, handy at the end of a pipeline
+fn toon(interpreter: &mut Interpreter, args: &[Value]) -> Result<Value, Message> {
+    println!("{}", interpreter.text_of(&args[0])?);
+    Ok(args[0].clone())
+}
+
+fn lengte(_: &mut Interpreter, args: &[Value]) -> Result<Value, Message> {
+    match &args[0] {
+        Value::List(list) => Ok(Value::Num(list.borrow().len() as f64)),
+        Value::Str(str) => Ok(Value::Num(str.chars().count() as f64)),
+        Value::Map(map) => Ok(Value::Num(map.borrow().len() as f64)),
+        value => Err(Code::NoLength.with(&[&value])),
+    }
+}
+
+fn getal(_: &mut Interpreter, args: &[Value]) -> Result<Value, Message> {
+    match &args[0] {
+        Value::Num(num) => Ok(Value::Num(*num)),
+        Value::Str(str) => match str.trim().parse::<f64>() {
+            Ok(num) => Ok(Value::Num(num)),
+            Err(_) => Err(Code::NotANumber.with(&[&str])),
+        },
+        value => Err(Code::NotANumber.with(&[&value])),
+    }
+}
+
+fn probeer_getal(interpreter: &mut Interpreter, args: &[Value]) -> Result<Value, Message> {
+    Ok(to_result(getal(interpreter, args)))
+}
+
+fn lees_bestand(_: &mut Interpreter, args: &[Value]) -> Result<Value, Message> {
+    match &args[0] {
+        Value::Str(path) => match fs::read_to_string(path) {
+            Ok(content) => Ok(Value::Str(content)),
+            Err(err) => Err(Code::FileUnreadable.with(&[&path, &err])),
+        },
+        value => Err(Code::ExpectedPath.with(&[&"lees_bestand", &value])),
+    }
+}
+
+fn probeer_lees_bestand(interpreter: &mut Interpreter, args: &[Value]) -> Result<Value, Message> {
+    Ok(to_result(lees_bestand(interpreter, args)))
+}
+
+// calls a proces without arguments, and turns the error it ran into into a Fout
+fn probeer(interpreter: &mut Interpreter, args: &[Value]) -> Result<Value, Message> {
+    let result = interpreter.call_value(&args[0], Vec::new());
+    if result.is_err() {
+        interpreter.catch_error();
+    }
+    Ok(to_result(result))
+}
+
+// the steps to the last error that probeer caught, as (wat, bestand, regel) with the outermost first
+fn laatste_spoor(interpreter: &mut Interpreter, _: &[Value]) -> Result<Value, Message> {
+    let frames = interpreter
+        .caught_trace()
+        .map(|frame| {
+            Value::Tuple(Rc::new(vec![
+                Value::Str(frame.construct.clone()),
+                Value::Str(frame.file()),
+                Value::Num(frame.span.line as f64),
+            ]))
+        })
+        .collect();
+    Ok(Value::from_vec(frames))
+}
+
+// gives back the limit, and changes it when a new one is given
+fn recursie_limiet(interpreter: &mut Interpreter, args: &[Value]) -> Result<Value, Message> {
+    let previous = interpreter.recursion_limit();
+    if let Some(limit) = args.first() {
+        let limit = num_arg("recursie_limiet", limit)?;
+        if limit < 1.0 || limit.fract() != 0.0 {
+            return Err(Code::RecursionLimitInvalid.with(&[&limit]));
+        }
+        interpreter.set_recursion_limit(limit as usize);
+    }
+    Ok(Value::Num(previous as f64))
+}
+
+fn generator_arg(name: &str, value: &Value) -> Result<Rc<RefCell<Generator>>, Message> {
+    match value {
+        Value::Generator(generator) => Ok(generator.clone()),
+        value => Err(Code::ExpectedGenerator.with(&[&name, &value])),
+    }
+}
+
+// the next value of a generator, or niks when it is done
+fn volgende(interpreter: &mut Interpreter, args: &[Value]) -> Result<Value, Message> {
+    let generator = generator_arg("volgende", &args[0])?;
+    let value = interpreter.resume_generator(&generator, Value::Nil)?;
+    Ok(value.unwrap_or(Value::Nil))
+}
+
+// like volgende, but the 'lever' the generator is waiting at gives back the value that is sent
+fn stuur(interpreter: &mut Interpreter, args: &[Value]) -> Result<Value, Message> {
+    let generator = generator_arg("stuur", &args[0])?;
+    let value = interpreter.resume_generator(&generator, args[1].clone())?;
+    Ok(value.unwrap_or(Value::Nil))
+}
+
+fn klaar(_: &mut Interpreter, args: &[Value]) -> Result<Value, Message> {
+    let generator = generator_arg("klaar", &args[0])?;
+    let is_done = generator.borrow().is_done();
+    Ok(Value::from_bool(is_done))
+}
+
+// starts the proces as a task, which only runs while the main program is in wacht
+fn taak(interpreter: &mut Interpreter, args: &[Value]) -> Result<Value, Message> {
+    let name = match &args[0] {
+        Value::Func(fun) => fun.name().to_string(),
+        Value::Native(native) => native.name.to_string(),
+        value => return Err(Code::ExpectedProces.with(&[&"taak", &value])),
+    };
+    let task = Task::new(name, args[0].clone(), args[1..].to_vec());
+    let task = Rc::new(RefCell::new(task));
+    interpreter.scheduler().spawn(task.clone());
+    Ok(Value::Task(task))
+}
+
+// lets the tasks run until the task, slaap or ontvang is done, tasks themselves wait with 'lever'
+fn wacht(interpreter: &mut Interpreter, args: &[Value]) -> Result<Value, Message> {
+    if interpreter.scheduler().in_task() {
+        return Err(Code::WaitInsideTask.with(&[&args[0]]));
+    }
+
+    let blocked = match &args[0] {
+        Value::Task(task) => Blocked::Task(task.clone()),
+        Value::Wait(wait) => match &**wait {
+            Wait::Sleep(ms) => Blocked::Time(interpreter.scheduler().clock.now() + ms),
+            Wait::Receive(channel) => Blocked::Receive(channel.clone()),
+        },
+        Value::List(_) | Value::Tuple(_) => {
+            let mut values = Vec::new();
+            for value in args[0].iter_values().unwrap_or_default() {
+                values.push(wacht(interpreter, &[value])?);
+            }
+            return Ok(Value::from_vec(values));
+        }
+        value => return Err(Code::WaitInvalid.with(&[&value])),
+    };
+    scheduler::run_until(interpreter, blocked)
+}
+
+fn slaap(_: &mut Interpreter, args: &[Value]) -> Result<Value, Message> {
+    let ms = num_arg("slaap", &args[0])?;
+    if ms < 0.0 {
+        return Err(Code::SleepInvalid.with(&[&ms]));
+    }
+    Ok(Value::Wait(Rc::new(Wait::Sleep(ms))))
+}
+
+fn kanaal(_: &mut Interpreter, _: &[Value]) -> Result<Value, Message> {
+    Ok(Value::Channel(Rc::new(RefCell::new(Channel::default()))))
+}
+
+fn channel_arg(name: &str, value: &Value) -> Result<Rc<RefCell<Channel>>, Message> {
+    match value {
+        Value::Channel(channel) => Ok(channel.clone()),
+        value => Err(Code::ExpectedChannel.with(&[&name, &value])),
+    }
+}
+
+// never has to wait, a kanaal holds on to as many values as it gets
+fn verstuur(_: &mut Interpreter, args: &[Value]) -> Result<Value, Message> {
+    let channel = channel_arg("verstuur", &args[0])?;
+    channel.borrow_mut().values.push_back(args[1].clone());
+    Ok(Value::Nil)
+}
+
+fn ontvang(_: &mut Interpreter, args: &[Value]) -> Result<Value, Message> {
+    let channel = channel_arg("ontvang", &args[0])?;
+    Ok(Value::Wait(Rc::new(Wait::Receive(channel))))
+}
+
+// the milliseconds since the start of the program, on the clock of the tasks
+fn nu(interpreter: &mut Interpreter, _: &[Value]) -> Result<Value, Message> {
+    Ok(Value::Num(interpreter.scheduler().clock.now()))
+}
+
+// from now on slaap doesn't really wait, time jumps forward when every task is waiting
+fn virtuele_klok(interpreter: &mut Interpreter, _: &[Value]) -> Result<Value, Message> {
+    interpreter.scheduler().use_virtual_clock();
+    Ok(Value::Nil)
+}
+
+fn num_arg(name: &str, value: &Value) -> Result<f64, Message> {
+    match value {
+        Value::Num(num) => Ok(*num),
+        value => Err(Code::ExpectedNumber.with(&[&name, &value])),
+    }
+}
+
+fn wortel(_: &mut Interpreter, args: &[Value]) -> Result<Value, Message> {
+    let num = num_arg("wortel", &args[0])?;
+    if num < 0.0 {
+        return Err(Code::NegativeRoot.with(&[&num]));
+    }
+    Ok(Value::Num(num.sqrt()))
+}
+
+fn abs(_: &mut Interpreter, args: &[Value]) -> Result<Value, Message> {
+    Ok(Value::Num(num_arg("abs", &args[0])?.abs()))
+}
+
+fn afronden(_: &mut Interpreter, args: &[Value]) -> Result<Value, Message> {
+    Ok(Value::Num(num_arg("afronden", &args[0])?.round()))
+}
+
+fn vloer(_: &mut Interpreter, args: &[Value]) -> Result<Value, Message> {
+    Ok(Value::Num(num_arg("vloer", &args[0])?.floor()))
+}
+
+fn plafond(_: &mut Interpreter, args: &[Value]) -> Result<Value, Message> {
+    Ok(Value::Num(num_arg("plafond", &args[0])?.ceil()))
+}
+
+fn sin(_: &mut Interpreter, args: &[Value]) -> Result<Value, Message> {
+    Ok(Value::Num(num_arg("sin", &args[0])?.sin()))
+}
+
+fn cos(_: &mut Interpreter, args: &[Value]) -> Result<Value, Message> {
+    Ok(Value::Num(num_arg("cos", &args[0])?.cos()))
+}
+
+// tuples, texts and maps can also be used wherever a list of values is expected
+fn list_arg(name: &str, value: &Value) -> Result<Vec<Value>, Message> {
+    match value.iter_values() {
+        Some(values) => Ok(values),
+        None => Err(Code::ExpectedList.with(&[&name, &value])),
+    }
+}
+
+fn voeg_toe(_: &mut Interpreter, args: &[Value]) -> Result<Value, Message> {
+    match &args[0] {
+        Value::List(list) => {
+            list.borrow_mut().push(args[1].clone());
+            Ok(args[0].clone())
+        }
+        value => Err(Code::ExpectedList.with(&[&"voeg_toe", &value])),
+    }
+}
+
+fn kaart_toe(interpreter: &mut Interpreter, args: &[Value]) -> Result<Value, Message> {
+    let mut values = Vec::new();
+    for value in list_arg("kaart_toe", &args[0])? {
+        values.push(interpreter.call_value(&args[1], vec![value])?);
+    }
+    Ok(Value::from_vec(values))
+}
+
+fn filter(interpreter: &mut Interpreter, args: &[Value]) -> Result<Value, Message> {
+    let mut values = Vec::new();
+    for value in list_arg("filter", &args[0])? {
+        match interpreter.call_value(&args[1], vec![value.clone()])? {
+            Value::True => values.push(value),
+            Value::False => (),
+            result => return Err(Code::CallbackMustReturnBool.with(&[&"filter", &result])),
+        }
+    }
+    Ok(Value::from_vec(values))
+}
+
+// vouw(lijst, proces, begin), without a begin the first value of the list is used
+fn vouw(interpreter: &mut Interpreter, args: &[Value]) -> Result<Value, Message> {
+    let mut values = list_arg("vouw", &args[0])?.into_iter();
+    let mut result = match args.get(2) {
+        Some(begin) => begin.clone(),
+        None => match values.next() {
+            Some(value) => value,
+            None => return Err(Code::FoldNeedsStart.into()),
+        },
+    };
+    for value in values {
+        result = interpreter.call_value(&args[1], vec![result, value])?;
+    }
+    Ok(result)
+}
+
+fn sorteer(interpreter: &mut Interpreter, args: &[Value]) -> Result<Value, Message> {
+    let values = list_arg("sorteer", &args[0])?;
+    let sorted = match args.get(1) {
+        Some(compare) => merge_sort(values, &mut |a, b| {
+            match interpreter.call_value(compare, vec![a.clone(), b.clone()])? {
+                // the proces says whether a comes before b
+                Value::True => Ok(true),
+                Value::False => Ok(false),
+                // or gives a number smaller than 0 when it does
+                Value::Num(num) => Ok(num < 0.0),
+                result => Err(Code::SortCallbackInvalid.with(&[&result])),
+            }
+        })?,
+        None => merge_sort(values, &mut |a, b| is_less("sorteer", a, b))?,
+    };
+    Ok(Value::from_vec(sorted))
+}
+
+// a stable sort that stops at the first error of the comparison
+fn merge_sort(
+    mut values: Vec<Value>,
+    is_less: &mut dyn FnMut(&Value, &Value) -> Result<bool, Message>,
+) -> Result<Vec<Value>, Message> {
+    if values.len() <= 1 {
+        return Ok(values);
+    }
+    let right = values.split_off(values.len() / 2);
+    let left = merge_sort(values, is_less)?;
+    let right = merge_sort(right, is_less)?;
+
+    let mut sorted = Vec::with_capacity(left.len() + right.len());
+    let mut left = left.into_iter().peekable();
+    let mut right = right.into_iter().peekable();
+    while let (Some(l), Some(r)) = (left.peek(), right.peek()) {
+        // only take from the right when it is really smaller, so equal values keep their order
+        if is_less(r, l)? {
+            sorted.extend(right.next());
+        } else {
+            sorted.extend(left.next());
+        }
+    }
+    sorted.extend(left);
+    sorted.extend(right);
+    Ok(sorted)
+}
+
+fn is_less(name: &str, a: &Value, b: &Value) -> Result<bool, Message> {
+    match (a, b) {
+        (Value::Num(num1), Value::Num(num2)) => Ok(num1 < num2),
+        (Value::Str(str1), Value::Str(str2)) => Ok(str1 < str2),
+        _ => Err(Code::CannotCompare.with(&[&name, &a, &b])),
+    }
+}
+
+fn zip(_: &mut Interpreter, args: &[Value]) -> Result<Value, Message> {
+    let values1 = list_arg("zip", &args[0])?;
+    let values2 = list_arg("zip", &args[1])?;
+    let pairs = (values1.into_iter())
+        .zip(values2)
+        .map(|(value1, value2)| Value::Tuple(Rc::new(vec![value1, value2])))
+        .collect();
+    Ok(Value::from_vec(pairs))
+}
+
+fn enumereer(_: &mut Interpreter, args: &[Value]) -> Result<Value, Message> {
+    let pairs = (list_arg("enumereer", &args[0])?.into_iter())
+        .enumerate()
+        .map(|(i, value)| Value::Tuple(Rc::new(vec![Value::Num(i as f64), value])))
+        .collect();
+    Ok(Value::from_vec(pairs))
+}
+
+fn som(_: &mut Interpreter, args: &[Value]) -> Result<Value, Message> {
+    let mut sum = 0.0;
+    for value in list_arg("som", &args[0])? {
+        sum += num_arg("som", &value)?;
+    }
+    Ok(Value::Num(sum))
+}
+
+fn min(_: &mut Interpreter, args: &[Value]) -> Result<Value, Message> {
+    let mut values = list_arg("min", &args[0])?.into_iter();
+    let mut smallest = match values.next() {
+        Some(value) => value,
+        None => return Err(Code::EmptyList.with(&[&"min"])),
+    };
+    for value in values {
+        if is_less("min", &value, &smallest)? {
+            smallest = value;
+        }
+    }
+    Ok(smallest)
+}
+
+fn max(_: &mut Interpreter, args: &[Value]) -> Result<Value, Message> {
+    let mut values = list_arg("max", &args[0])?.into_iter();
+    let mut largest = match values.next() {
+        Some(value) => value,
+        None => return Err(Code::EmptyList.with(&[&"max"])),
+    };
+    for value in values {
+        if is_less("max", &largest, &value)? {
+            largest = value;
+        }
+    }
+    Ok(largest)
+}
+
+fn omgekeerd(_: &mut Interpreter, args: &[Value]) -> Result<Value, Message> {
+    match &args[0] {
+        Value::Str(str) => Ok(Value::Str(str.chars().rev().collect())),
+        value => {
+            let mut values = list_arg("omgekeerd", value)?;
+            values.reverse();
+            Ok(Value::from_vec(values))
+        }
+    }
+}
@@ -1,55 +1,498 @@
+use std::{path::Path, rc::Rc};
+
 use crate::{
-    crash,
-    expr::Expr,
-    stmt::{If, Stmt},
+    error::RoxError,
+    expr::{Arg, Clause, Expr, Variable},
+    messages::{Code, Text},
+    scanner,
+    stmt::{Class, Fun, If, Import, Param, Pattern, Stmt, Trait},
+    suggest,
     token::{Literal, Token},
     token_type::TokenType,
 };
 
+pub const MAX_ERRORS: usize = 20;
+
 pub struct Parser {
     tokens: Vec<Token>,
     current: usize,
+    fun_depth: usize,
+    // 'dit' can only be used inside the methods of a klas or eigenschap
+    class_depth: usize,
+    errors: Vec<RoxError>,
+    // parsing stops after this many errors, the rest is mostly noise
+    pub max_errors: usize,
 }
 impl Parser {
     pub fn new(tokens: Vec<Token>) -> Parser {
-        Parser { tokens, current: 0 }
+        Parser {
+            tokens,
+            current: 0,
+            fun_depth: 0,
+            class_depth: 0,
+            errors: Vec::new(),
+            max_errors: MAX_ERRORS,
+        }
     }
 
-    pub fn parse_expr(&mut self) -> Expr {
+    pub fn parse_expr(&mut self) -> Result<Expr, RoxError> {
         self.expression()
     }
 
-    pub fn parse_statements(&mut self) -> Vec<Stmt> {
+    // keeps going after a syntax error, so one run shows all of them
+    pub fn parse_statements(&mut self) -> Result<Vec<Stmt>, Vec<RoxError>> {
         let mut statements = Vec::new();
+        while !self.is_at_end() && !self.too_many_errors() {
+            let start = self.current;
+            match self.top_level_declaration() {
+                Ok(stmt) => statements.push(stmt),
+                Err(error) => {
+                    self.fun_depth = 0;
+                    self.class_depth = 0;
+                    self.synchronize(error, false, start);
+                }
+            }
+        }
+
+        if self.errors.is_empty() {
+            Ok(statements)
+        } else {
+            let mut errors = std::mem::take(&mut self.errors);
+            errors.truncate(self.max_errors);
+            Err(errors)
+        }
+    }
+
+    fn too_many_errors(&self) -> bool {
+        self.errors.len() >= self.max_errors
+    }
+
+    // skips to the start of the next statement, so one mistake doesn't cause a pile of others
+    // start is where the failed statement began, so there is always some progress
+    fn synchronize(&mut self, error: RoxError, in_block: bool, start: usize) {
+        let error = match error.help {
+            Some(_) => error,
+            None => {
+                let help = self.keyword_typo(&self.tokens[start]);
+                error.with_help(help)
+            }
+        };
+        self.errors.push(error);
+
+        // inside a block the '}' is left for the block itself
+        if in_block && self.check(TokenType::RightBrace) {
+            return;
+        }
+        // after a forgotten ';' the next statement is still fine
+        if self.current > start && self.at_statement_start() {
+            return;
+        }
+        self.advance();
+
         while !self.is_at_end() {
-            statements.push(self.declaration());
+            match self.previous().kind {
+                TokenType::Semicolon | TokenType::RightBrace => return,
+                _ => {}
+            }
+            if self.at_statement_start() || (in_block && self.check(TokenType::RightBrace)) {
+                return;
+            }
+            self.advance();
+        }
+    }
+
+    // 'terwil x < 3' is parsed as the name terwil, which then goes wrong
+    fn keyword_typo(&self, token: &Token) -> Option<String> {
+        if token.kind != TokenType::Identifier {
+            return None;
+        }
+        let keywords = scanner::keywords();
+        let close = suggest::closest(&token.lexeme, keywords.keys().map(String::as_str));
+        suggest::did_you_mean(&close)
+    }
+
+    fn at_statement_start(&self) -> bool {
+        matches!(
+            self.peek().kind,
+            TokenType::Var
+                | TokenType::Const
+                | TokenType::If
+                | TokenType::While
+                | TokenType::For
+                | TokenType::Fun
+                | TokenType::Class
+                | TokenType::Trait
+                | TokenType::Record
+                | TokenType::Return
+                | TokenType::Print
+                | TokenType::Println
+                | TokenType::Import
+                | TokenType::Export
+        )
+    }
+
+    // imports and exports are only allowed at the top of a file, not inside blocks or processes
+    fn top_level_declaration(&mut self) -> Result<Stmt, RoxError> {
+        if self.matches(vec![TokenType::Import]) {
+            return self.import_declaration();
+        } else if self.matches(vec![TokenType::Export]) {
+            return self.export_declaration();
         }
-        statements
+        self.declaration()
     }
 
-    fn declaration(&mut self) -> Stmt {
+    fn declaration(&mut self) -> Result<Stmt, RoxError> {
         if self.matches(vec![TokenType::Var]) {
             return self.var_declaration();
+        } else if self.matches(vec![TokenType::Const]) {
+            return self.const_declaration();
+        } else if self.check(TokenType::Fun) && self.check_next(TokenType::Identifier) {
+            self.advance();
+            return self.fun_declaration();
+        } else if self.matches(vec![TokenType::Record]) {
+            return self.record_declaration();
+        } else if self.matches(vec![TokenType::Class]) {
+            return self.class_declaration();
+        } else if self.matches(vec![TokenType::Trait]) {
+            return self.trait_declaration();
+        } else if self.matches(vec![TokenType::Import, TokenType::Export]) {
+            let msg = Code::ImportNotAtTop.with(&[&self.previous().lexeme]);
+            return Err(RoxError::parse(&self.previous().span, msg));
         }
         self.statement()
     }
 
-    fn var_declaration(&mut self) -> Stmt {
-        let name = self.consume(
-            TokenType::Identifier,
-            "Je moet wel een naam aan de variabele geven",
-        );
+    fn import_declaration(&mut self) -> Result<Stmt, RoxError> {
+        let keyword = self.previous();
+        if !self.matches(vec![TokenType::String, TokenType::Identifier]) {
+            return Err(RoxError::parse(&keyword.span, Code::ExpectedModulePath));
+        }
+        let path = self.previous();
+
+        // the module is named after the file, unless it is renamed with 'als'
+        let mut name = path.clone();
+        if let Literal::Str(str) = &path.literal {
+            match Path::new(str).file_stem() {
+                Some(stem) => name.rename(stem.to_string_lossy().to_string()),
+                None => return Err(RoxError::parse(&path.span, Code::InvalidPath.with(&[str]))),
+            }
+        }
+        if self.matches(vec![TokenType::If]) {
+            name = self.consume(TokenType::Identifier, Code::ExpectedModuleName)?;
+        }
+
+        self.consume(TokenType::Semicolon, Code::MissingSemicolon)?;
+        Ok(Stmt::Import(Import::new(keyword, path, name)))
+    }
+
+    fn export_declaration(&mut self) -> Result<Stmt, RoxError> {
+        let stmt = if self.matches(vec![TokenType::Var]) {
+            self.var_declaration()?
+        } else if self.matches(vec![TokenType::Const]) {
+            self.const_declaration()?
+        } else if self.matches(vec![TokenType::Fun]) {
+            self.fun_declaration()?
+        } else if self.matches(vec![TokenType::Record]) {
+            self.record_declaration()?
+        } else if self.matches(vec![TokenType::Class]) {
+            self.class_declaration()?
+        } else if self.matches(vec![TokenType::Trait]) {
+            self.trait_declaration()?
+        } else {
+            return Err(RoxError::parse(&self.peek().span, Code::InvalidExport));
+        };
+
+        let names = match &stmt {
+            Stmt::Var(name, _) | Stmt::Const(name, _) => vec![name.clone()],
+            Stmt::Destructure(pattern, _) => pattern.names(),
+            Stmt::Fun(fun) => vec![fun.name.clone()],
+            Stmt::Record(name, _) => vec![name.clone()],
+            Stmt::Class(class) => vec![class.name.clone()],
+            Stmt::Trait(trait_decl) => vec![trait_decl.name.clone()],
+            _ => panic!("Unreachable."),
+        };
+        Ok(Stmt::Export(names, Box::new(stmt)))
+    }
+
+    fn fun_declaration(&mut self) -> Result<Stmt, RoxError> {
+        let name = self.consume(TokenType::Identifier, Code::ExpectedProcesName)?;
+        self.consume(TokenType::LeftParen, Code::ExpectedParenAfterProcesName)?;
+        Ok(Stmt::Fun(Rc::new(self.function(name)?)))
+    }
+
+    // the parameters and body of a proces, starting after the '('
+    fn function(&mut self, name: Token) -> Result<Fun, RoxError> {
+        let params = self.parameters()?;
+        self.function_body(name, params)
+    }
+
+    fn function_body(&mut self, name: Token, params: Vec<Param>) -> Result<Fun, RoxError> {
+        self.consume(TokenType::LeftBrace, Code::ExpectedProcesBody)?;
+
+        self.fun_depth += 1;
+        let body = match self.block_statement()? {
+            Stmt::Block(statements) => statements,
+            _ => panic!("Unreachable."),
+        };
+        self.fun_depth -= 1;
+
+        Ok(Fun::new(name, params, body))
+    }
+
+    fn parameters(&mut self) -> Result<Vec<Param>, RoxError> {
+        let mut params: Vec<Param> = Vec::new();
+        if !self.check(TokenType::RightParen) {
+            loop {
+                let rest = self.matches(vec![TokenType::Ellipsis]);
+                let name = self.consume(TokenType::Identifier, Code::ExpectedParamName)?;
+                if params.iter().any(|p| p.name.lexeme == name.lexeme) {
+                    let msg = Code::DuplicateParam.with(&[&name.lexeme]);
+                    return Err(RoxError::parse(&name.span, msg));
+                }
+
+                let mut default = None;
+                if !rest && self.matches(vec![TokenType::Equal]) {
+                    default = Some(self.expression()?);
+                } else if !rest && params.iter().any(|p| p.default.is_some()) {
+                    let msg = Code::DefaultNeeded.with(&[&name.lexeme]);
+                    return Err(RoxError::parse(&name.span, msg));
+                }
+
+                params.push(Param::new(name, default, rest));
+                if rest && !self.check(TokenType::RightParen) {
+                    return Err(RoxError::parse(&self.peek().span, Code::ParamAfterRest));
+                }
+                if !self.matches(vec![TokenType::Comma]) {
+                    break;
+                }
+            }
+        }
+        self.consume(TokenType::RightParen, Code::MissingParen)?;
+        Ok(params)
+    }
+
+    // proces (x) { retour x * 2; }
+    fn lambda(&mut self) -> Result<Expr, RoxError> {
+        let mut name = self.previous();
+        name.rename("anoniem".to_string());
+        self.consume(TokenType::LeftParen, Code::ExpectedParenAfterProces)?;
+        Ok(Expr::Lambda(Rc::new(self.function(name)?)))
+    }
+
+    // (x) => x * 2, the '(' has already been matched
+    fn arrow_function(&mut self) -> Result<Expr, RoxError> {
+        let mut name = self.previous();
+        name.rename("anoniem".to_string());
+        let params = self.parameters()?;
+        let arrow = self.consume(TokenType::Arrow, Code::ExpectedArrow)?;
+
+        let body = vec![Stmt::Return(arrow, self.expression()?)];
+        Ok(Expr::Lambda(Rc::new(Fun::new(name, params, body))))
+    }
+
+    // looks ahead for a '=>' right after the matching ')', without consuming anything
+    fn is_arrow_function(&self) -> bool {
+        let mut depth = 0;
+        for i in self.current..self.tokens.len() {
+            match self.tokens[i].kind {
+                TokenType::LeftParen => depth += 1,
+                TokenType::RightParen => {
+                    depth -= 1;
+                    // (x) -> x * 2 is meant as a short proces too, so it gets the error about '=>'
+                    if depth == 0 {
+                        let next = |n: usize| self.tokens.get(i + n).map(|token| token.kind);
+                        return next(1) == Some(TokenType::Arrow)
+                            || (next(1) == Some(TokenType::Minus)
+                                && next(2) == Some(TokenType::Greater));
+                    }
+                }
+                TokenType::Eof => return false,
+                _ => (),
+            }
+        }
+        false
+    }
+
+    // record Punt(x, y);
+    fn record_declaration(&mut self) -> Result<Stmt, RoxError> {
+        let name = self.consume(TokenType::Identifier, Code::ExpectedRecordName)?;
+        self.consume(TokenType::LeftParen, Code::ExpectedParenAfterRecordName)?;
+
+        let mut fields = Vec::new();
+        if !self.check(TokenType::RightParen) {
+            loop {
+                let field = self.consume(TokenType::Identifier, Code::ExpectedFieldName)?;
+                if fields.iter().any(|f: &Token| f.lexeme == field.lexeme) {
+                    let msg = Code::DuplicateField.with(&[&field.lexeme]);
+                    return Err(RoxError::parse(&field.span, msg));
+                }
+                fields.push(field);
+                if !self.matches(vec![TokenType::Comma]) {
+                    break;
+                }
+            }
+        }
+        self.consume(TokenType::RightParen, Code::MissingParen)?;
+        self.consume(TokenType::Semicolon, Code::MissingSemicolon)?;
+
+        Ok(Stmt::Record(name, fields))
+    }
+
+    fn class_declaration(&mut self) -> Result<Stmt, RoxError> {
+        let name = self.consume(TokenType::Identifier, Code::ExpectedClassName)?;
+        let mut traits = Vec::new();
+        if self.matches(vec![TokenType::Implements]) {
+            loop {
+                let name = self.consume(TokenType::Identifier, Code::ExpectedTraitName)?;
+                traits.push(Variable::new(name));
+                if !self.matches(vec![TokenType::Comma]) {
+                    break;
+                }
+            }
+        }
+        self.consume(TokenType::LeftBrace, Code::ExpectedClassBody)?;
+
+        self.class_depth += 1;
+        let mut consts = Vec::new();
+        let mut methods: Vec<Rc<Fun>> = Vec::new();
+        while !self.check(TokenType::RightBrace) && !self.is_at_end() {
+            if self.matches(vec![TokenType::Const]) {
+                // vast naam, leeftijd;
+                loop {
+                    consts.push(self.consume(TokenType::Identifier, Code::ExpectedFieldName)?);
+                    if !self.matches(vec![TokenType::Comma]) {
+                        break;
+                    }
+                }
+                self.consume(TokenType::Semicolon, Code::MissingSemicolon)?;
+                continue;
+            }
+
+            let method = self.method_name(&methods)?;
+            self.consume(TokenType::LeftParen, Code::ExpectedParenAfterMethodName)?;
+            methods.push(Rc::new(self.function(method)?));
+        }
+        self.class_depth -= 1;
+
+        self.consume(TokenType::RightBrace, Code::MissingBrace)?;
+        Ok(Stmt::Class(Class::new(name, traits, consts, methods)))
+    }
+
+    fn trait_declaration(&mut self) -> Result<Stmt, RoxError> {
+        let name = self.consume(TokenType::Identifier, Code::ExpectedTraitName)?;
+        self.consume(TokenType::LeftBrace, Code::ExpectedTraitBody)?;
+
+        self.class_depth += 1;
+        let mut required = Vec::new();
+        let mut methods: Vec<Rc<Fun>> = Vec::new();
+        while !self.check(TokenType::RightBrace) && !self.is_at_end() {
+            let method = self.method_name(&methods)?;
+            if required.iter().any(|r: &Token| r.lexeme == method.lexeme) {
+                let msg = Code::DuplicateMethod.with(&[&method.lexeme]);
+                return Err(RoxError::parse(&method.span, msg));
+            }
+            self.consume(TokenType::LeftParen, Code::ExpectedParenAfterMethodName)?;
+
+            // a method without a body has to be written by every klas that implements it
+            let params = self.parameters()?;
+            if self.matches(vec![TokenType::Semicolon]) {
+                required.push(method);
+            } else {
+                methods.push(Rc::new(self.function_body(method, params)?));
+            }
+        }
+        self.class_depth -= 1;
+
+        self.consume(TokenType::RightBrace, Code::MissingBrace)?;
+        Ok(Stmt::Trait(Trait::new(name, required, methods)))
+    }
+
+    fn method_name(&mut self, methods: &[Rc<Fun>]) -> Result<Token, RoxError> {
+        let name = self.consume(TokenType::Identifier, Code::ExpectedMethodName)?;
+        if methods.iter().any(|m| m.name.lexeme == name.lexeme) {
+            let msg = Code::DuplicateMethod.with(&[&name.lexeme]);
+            return Err(RoxError::parse(&name.span, msg));
+        }
+        Ok(name)
+    }
+
+    fn var_declaration(&mut self) -> Result<Stmt, RoxError> {
+        if self.check(TokenType::LeftBracket)
+            || self.check(TokenType::LeftBrace)
+            || self.check(TokenType::LeftParen)
+        {
+            let pattern = self.pattern()?;
+            self.consume(TokenType::Equal, Code::PatternNeedsValue)?;
+            let value = self.expression()?;
+
+            self.consume(TokenType::Semicolon, Code::MissingSemicolon)?;
+            return Ok(Stmt::Destructure(pattern, value));
+        }
+
+        let name = self.consume(TokenType::Identifier, Code::ExpectedVariableName)?;
 
         let mut value = Expr::Lit(Literal::Nil);
         if self.matches(vec![TokenType::Equal]) {
-            value = self.expression();
+            value = match self.matches(vec![TokenType::Yield]) {
+                true => self.yield_expr()?,
+                false => self.expression()?,
+            };
+        }
+
+        self.consume(TokenType::Semicolon, Code::MissingSemicolon)?;
+        Ok(Stmt::Var(name, value))
+    }
+
+    fn pattern(&mut self) -> Result<Pattern, RoxError> {
+        if self.matches(vec![TokenType::LeftBracket, TokenType::LeftParen]) {
+            // [a, b] and (a, b) both match lists as well as tuples
+            let bracket = self.previous();
+            let (closing, msg) = match bracket.kind {
+                TokenType::LeftBracket => (TokenType::RightBracket, Code::MissingBracket),
+                _ => (TokenType::RightParen, Code::MissingParen),
+            };
+
+            let mut patterns = Vec::new();
+            if !self.check(closing) {
+                loop {
+                    patterns.push(self.pattern()?);
+                    if !self.matches(vec![TokenType::Comma]) {
+                        break;
+                    }
+                }
+            }
+            self.consume(closing, msg)?;
+            Ok(Pattern::List(bracket, patterns))
+        } else if self.matches(vec![TokenType::LeftBrace]) {
+            let mut names = Vec::new();
+            if !self.check(TokenType::RightBrace) {
+                loop {
+                    names.push(self.consume(TokenType::Identifier, Code::ExpectedFieldName)?);
+                    if !self.matches(vec![TokenType::Comma]) {
+                        break;
+                    }
+                }
+            }
+            self.consume(TokenType::RightBrace, Code::MissingBrace)?;
+            Ok(Pattern::Fields(names))
+        } else {
+            Ok(Pattern::Name(self.consume(
+                TokenType::Identifier,
+                Code::ExpectedVariableName,
+            )?))
         }
+    }
 
-        self.consume(TokenType::Semicolon, "Je bent de ';' vergeten druiloor");
-        Stmt::Var(name, value)
+    fn const_declaration(&mut self) -> Result<Stmt, RoxError> {
+        let name = self.consume(TokenType::Identifier, Code::ExpectedConstName)?;
+        self.consume(TokenType::Equal, Code::ConstNeedsValue)?;
+        let value = self.expression()?;
+
+        self.consume(TokenType::Semicolon, Code::MissingSemicolon)?;
+        Ok(Stmt::Const(name, value))
     }
 
-    fn statement(&mut self) -> Stmt {
+    fn statement(&mut self) -> Result<Stmt, RoxError> {
         if self.matches(vec![TokenType::Print]) {
             return self.print_statement();
         } else if self.matches(vec![TokenType::Println]) {
@@ -62,241 +505,542 @@ impl Parser {
             return self.while_statement();
         } else if self.matches(vec![TokenType::For]) {
             return self.for_statement();
+        } else if self.matches(vec![TokenType::Return]) {
+            return self.return_statement();
+        } else if self.matches(vec![TokenType::Yield]) {
+            let expr = self.yield_expr()?;
+            self.consume(TokenType::Semicolon, Code::MissingSemicolon)?;
+            return Ok(Stmt::Expr(expr));
         }
         self.expr_statement()
     }
 
-    fn block_statement(&mut self) -> Stmt {
+    fn block_statement(&mut self) -> Result<Stmt, RoxError> {
+        let brace = self.previous();
         let mut statements = Vec::new();
         while !self.check(TokenType::RightBrace) && !self.is_at_end() {
-            statements.push(self.declaration());
+            if self.too_many_errors() {
+                break;
+            }
+            let start = self.current;
+            let depths = (self.fun_depth, self.class_depth);
+            match self.declaration() {
+                Ok(stmt) => statements.push(stmt),
+                Err(error) => {
+                    (self.fun_depth, self.class_depth) = depths;
+                    self.synchronize(error, true, start);
+                }
+            }
         }
 
-        self.consume(TokenType::RightBrace, "je bent een '}' vergeten druiloor");
-        Stmt::Block(statements)
+        self.consume(TokenType::RightBrace, Code::MissingBrace)
+            .map_err(|error| error.with_label(&brace.span, Text::BlockStartsHere.to_string()))?;
+        Ok(Stmt::Block(statements))
     }
 
-    fn if_statement(&mut self) -> Stmt {
-        let first_if = If::new(self.expression(), self.statement());
+    fn if_statement(&mut self) -> Result<Stmt, RoxError> {
+        let first_if = If::new(self.expression()?, self.statement()?);
 
         let mut else_ifs = Vec::new();
 
         let mut other = None;
         while self.matches(vec![TokenType::Else]) {
             if self.matches(vec![TokenType::If]) {
-                let else_if = If::new(self.expression(), self.statement());
+                let else_if = If::new(self.expression()?, self.statement()?);
                 else_ifs.push(else_if);
             } else {
-                other = Some(Box::new(self.statement()));
+                other = Some(Box::new(self.statement()?));
                 break;
             }
         }
 
-        Stmt::If(first_if, else_ifs, other)
+        Ok(Stmt::If(first_if, else_ifs, other))
+    }
+
+    fn while_statement(&mut self) -> Result<Stmt, RoxError> {
+        let keyword = self.previous();
+        let expr = self.expression()?;
+        let statement = self.statement()?;
+
+        Ok(Stmt::While(keyword, expr, Box::new(statement)))
+    }
+
+    fn for_statement(&mut self) -> Result<Stmt, RoxError> {
+        // 'voor i = 0 tot 10' is meant as a counting loop too, so it gets the error about 'van'
+        if !self.check_next(TokenType::From) && !self.check_next(TokenType::Equal) {
+            return self.for_in_statement();
+        }
+
+        let name = self.consume(TokenType::Identifier, Code::ExpectedVariableName)?;
+        self.consume(TokenType::From, Code::ExpectedVan)?;
+
+        let start = self.expression()?;
+        self.consume(TokenType::Until, Code::ExpectedTot)?;
+        let end = self.expression()?;
+
+        let statement = self.statement()?;
+
+        Ok(Stmt::For(name, start, end, Box::new(statement)))
     }
 
-    fn while_statement(&mut self) -> Stmt {
-        let expr = self.expression();
-        let statement = self.statement();
+    // voor x in lijst, where the list can also be a map, tuple, string or generator
+    fn for_in_statement(&mut self) -> Result<Stmt, RoxError> {
+        let pattern = self.pattern()?;
+        let keyword = self.consume(TokenType::In, Code::ExpectedVanOrIn)?;
+        let iterable = self.expression()?;
+        let statement = self.statement()?;
 
-        Stmt::While(expr, Box::new(statement))
+        Ok(Stmt::ForIn(pattern, keyword, iterable, Box::new(statement)))
     }
 
-    fn for_statement(&mut self) -> Stmt {
-        let name = self.consume(
-            TokenType::Identifier,
-            "Je moet wel een naam aan de variabele geven.",
-        );
-        self.consume(TokenType::From, "Verwachtte 'van'.");
+    fn return_statement(&mut self) -> Result<Stmt, RoxError> {
+        let keyword = self.previous();
+        if self.fun_depth == 0 {
+            return Err(RoxError::parse(&keyword.span, Code::ReturnOutsideProces));
+        }
+
+        let mut value = Expr::Lit(Literal::Nil);
+        if !self.check(TokenType::Semicolon) {
+            value = self.expression()?;
+        }
+
+        self.consume(TokenType::Semicolon, Code::MissingSemicolon)?;
+        Ok(Stmt::Return(keyword, value))
+    }
 
-        let start = self.expression();
-        self.consume(TokenType::Until, "Verwachtte 'tot'.");
-        let end = self.expression();
+    // lever can only be a statement on its own or the value of 'laat' and '=', the 'lever' is already matched
+    fn yield_expr(&mut self) -> Result<Expr, RoxError> {
+        let keyword = self.previous();
+        if self.fun_depth == 0 {
+            return Err(RoxError::parse(&keyword.span, Code::YieldOutsideProces));
+        }
 
-        let statement = self.statement();
+        let mut value = Expr::Lit(Literal::Nil);
+        if !self.check(TokenType::Semicolon) {
+            value = self.expression()?;
+        }
+        Ok(Expr::Yield(keyword, Box::new(value)))
+    }
 
-        Stmt::For(name, start, end, Box::new(statement))
+    fn print_statement(&mut self) -> Result<Stmt, RoxError> {
+        let expr = self.expression()?;
+        self.consume(TokenType::Semicolon, Code::MissingSemicolon)?;
+        Ok(Stmt::Print(expr))
     }
 
-    fn print_statement(&mut self) -> Stmt {
-        let expr = self.expression();
-        self.consume(TokenType::Semicolon, "Je bent een ';' vergeten druiloor");
-        Stmt::Print(expr)
+    fn println_statement(&mut self) -> Result<Stmt, RoxError> {
+        let expr = self.expression()?;
+        self.consume(TokenType::Semicolon, Code::MissingSemicolon)?;
+        Ok(Stmt::Println(expr))
     }
 
-    fn println_statement(&mut self) -> Stmt {
-        let expr = self.expression();
-        self.consume(TokenType::Semicolon, "Je bent een ';' vergeten druiloor");
-        Stmt::Println(expr)
+    fn expr_statement(&mut self) -> Result<Stmt, RoxError> {
+        // x = lever y;
+        if self.check(TokenType::Identifier)
+            && self.check_next(TokenType::Equal)
+            && self.tokens[self.current + 2].kind == TokenType::Yield
+        {
+            let name = self.advance();
+            self.current += 2;
+            let value = self.yield_expr()?;
+            self.consume(TokenType::Semicolon, Code::MissingSemicolon)?;
+            return Ok(Stmt::Expr(Expr::Assign(
+                Variable::new(name),
+                Box::new(value),
+            )));
+        }
+
+        let expr = self.expression()?;
+        if let (Expr::Var(first), true) = (&expr, self.check(TokenType::Comma)) {
+            return self.multi_assignment(first.clone());
+        }
+
+        self.consume(TokenType::Semicolon, Code::MissingSemicolon)?;
+        Ok(Stmt::Expr(expr))
     }
 
-    fn expr_statement(&mut self) -> Stmt {
-        let expr = self.expression();
-        self.consume(TokenType::Semicolon, "Je bent een ';' vergeten druiloor");
-        Stmt::Expr(expr)
+    // a, b = b, a
+    fn multi_assignment(&mut self, first: Variable) -> Result<Stmt, RoxError> {
+        let mut names = vec![first];
+        while self.matches(vec![TokenType::Comma]) {
+            let name = self.consume(TokenType::Identifier, Code::AssignOnlyVariables)?;
+            names.push(Variable::new(name));
+        }
+        let equals = self.consume(TokenType::Equal, Code::ExpectedEqualsAfterNames)?;
+
+        let mut values = vec![self.expression()?];
+        while self.matches(vec![TokenType::Comma]) {
+            values.push(self.expression()?);
+        }
+
+        // a single value on the right is a list that gets destructured when it is run
+        if values.len() != 1 && values.len() != names.len() {
+            let msg = Code::AssignCountMismatch.with(&[&names.len(), &values.len()]);
+            return Err(RoxError::parse(&equals.span, msg));
+        }
+
+        self.consume(TokenType::Semicolon, Code::MissingSemicolon)?;
+        Ok(Stmt::MultiAssign(names, equals, values))
     }
 
-    fn expression(&mut self) -> Expr {
+    fn expression(&mut self) -> Result<Expr, RoxError> {
         self.assignment()
     }
 
-    fn assignment(&mut self) -> Expr {
-        let expr = self.or();
+    fn assignment(&mut self) -> Result<Expr, RoxError> {
+        let expr = self.pipe()?;
 
         if self.matches(vec![TokenType::Equal]) {
             let equals = self.previous();
-            let value = self.assignment();
+            let value = self.assignment()?;
 
             match expr {
-                Expr::Var(name) => return Expr::Assign(name, Box::new(value)),
-                _ => crash(equals.line, "dit kan je niet assignen."),
+                Expr::Var(name) => return Ok(Expr::Assign(name, Box::new(value))),
+                Expr::Get(object, name) => return Ok(Expr::Set(object, name, Box::new(value))),
+                Expr::Index(list, bracket, index) => {
+                    return Ok(Expr::SetIndex(list, bracket, index, Box::new(value)))
+                }
+                _ => return Err(RoxError::parse(&equals.span, Code::InvalidAssignTarget)),
             }
         }
 
-        expr
+        Ok(expr)
+    }
+
+    // lijst |> filter(f) |> sorteer(), the value on the left becomes the first argument
+    fn pipe(&mut self) -> Result<Expr, RoxError> {
+        let mut expr = self.or()?;
+
+        while self.matches(vec![TokenType::Pipe]) {
+            let op = self.previous();
+            let right = self.or()?;
+            expr = Expr::Pipe(Box::new(expr), op, Box::new(right));
+        }
+
+        Ok(expr)
     }
 
-    fn or(&mut self) -> Expr {
-        let left = self.and();
+    fn or(&mut self) -> Result<Expr, RoxError> {
+        let mut left = self.and()?;
 
         while self.matches(vec![TokenType::Or]) {
             let op = self.previous();
-            let right = self.and();
-            return Expr::Logic(Box::new(left), op, Box::new(right));
+            let right = self.and()?;
+            left = Expr::Logic(Box::new(left), op, Box::new(right));
         }
 
-        left
+        Ok(left)
     }
 
-    fn and(&mut self) -> Expr {
-        let left = self.equality();
+    fn and(&mut self) -> Result<Expr, RoxError> {
+        let mut left = self.equality()?;
 
         while self.matches(vec![TokenType::And]) {
             let op = self.previous();
-            let right = self.equality();
-            return Expr::Logic(Box::new(left), op, Box::new(right));
+            let right = self.equality()?;
+            left = Expr::Logic(Box::new(left), op, Box::new(right));
         }
 
-        left
+        Ok(left)
     }
 
-    fn equality(&mut self) -> Expr {
-        let mut expr = self.comparison();
+    fn equality(&mut self) -> Result<Expr, RoxError> {
+        let mut expr = self.comparison()?;
 
         while self.matches(vec![TokenType::BangEqual, TokenType::EqualEqual]) {
             let op = self.previous();
-            let right = self.comparison();
+            let right = self.comparison()?;
             expr = Expr::Binary(Box::new(expr), op, Box::new(right));
         }
 
-        expr
+        Ok(expr)
     }
 
-    fn comparison(&mut self) -> Expr {
-        let mut expr = self.term();
+    fn comparison(&mut self) -> Result<Expr, RoxError> {
+        let mut expr = self.term()?;
 
         while self.matches(vec![
             TokenType::Greater,
             TokenType::GreaterEqual,
             TokenType::Less,
             TokenType::LessEqual,
+            TokenType::Is,
+            TokenType::Implements,
         ]) {
             let op = self.previous();
-            let right = self.term();
+            let right = self.term()?;
             expr = Expr::Binary(Box::new(expr), op, Box::new(right));
         }
 
-        expr
+        Ok(expr)
     }
 
-    fn term(&mut self) -> Expr {
-        let mut expr = self.factor();
+    fn term(&mut self) -> Result<Expr, RoxError> {
+        let mut expr = self.factor()?;
 
         while self.matches(vec![TokenType::Plus, TokenType::Minus]) {
             let op = self.previous();
-            let right = self.factor();
+            let right = self.factor()?;
             expr = Expr::Binary(Box::new(expr), op, Box::new(right));
         }
 
-        expr
+        Ok(expr)
     }
 
-    fn factor(&mut self) -> Expr {
-        let mut expr = self.unary();
+    fn factor(&mut self) -> Result<Expr, RoxError> {
+        let mut expr = self.unary()?;
 
         while self.matches(vec![TokenType::Star, TokenType::Slash]) {
             let op = self.previous();
-            let right = self.unary();
+            let right = self.unary()?;
             expr = Expr::Binary(Box::new(expr), op, Box::new(right));
         }
 
-        expr
+        Ok(expr)
     }
 
-    fn unary(&mut self) -> Expr {
+    fn unary(&mut self) -> Result<Expr, RoxError> {
         if self.matches(vec![TokenType::Bang, TokenType::Minus]) {
             let op = self.previous();
-            let right = self.power();
-            return Expr::Unary(op, Box::new(right));
+            let right = self.power()?;
+            return Ok(Expr::Unary(op, Box::new(right)));
         }
 
         self.power()
     }
 
-    fn power(&mut self) -> Expr {
-        let mut expr = self.primary();
+    fn power(&mut self) -> Result<Expr, RoxError> {
+        let mut expr = self.call()?;
 
         while self.matches(vec![TokenType::Caret]) {
             let op = self.previous();
-            let right = self.primary();
+            let right = self.call()?;
             expr = Expr::Binary(Box::new(expr), op, Box::new(right));
         }
 
-        expr
+        Ok(expr)
     }
 
-    fn primary(&mut self) -> Expr {
+    fn call(&mut self) -> Result<Expr, RoxError> {
+        let mut expr = self.primary()?;
+
+        loop {
+            if self.matches(vec![TokenType::LeftParen]) {
+                expr = self.finish_call(expr)?;
+            } else if self.matches(vec![TokenType::Question]) {
+                expr = Expr::Try(Box::new(expr), self.previous());
+            } else if self.matches(vec![TokenType::LeftBracket]) {
+                let bracket = self.previous();
+                let index = self.expression()?;
+                self.consume(TokenType::RightBracket, Code::MissingBracket)?;
+                expr = Expr::Index(Box::new(expr), bracket, Box::new(index));
+            } else if self.matches(vec![TokenType::Dot]) {
+                let name = self.consume(TokenType::Identifier, Code::ExpectedNameAfterDot)?;
+                expr = Expr::Get(Box::new(expr), name);
+            } else {
+                break;
+            }
+        }
+
+        Ok(expr)
+    }
+
+    fn finish_call(&mut self, callee: Expr) -> Result<Expr, RoxError> {
+        let mut args = Vec::new();
+        if !self.check(TokenType::RightParen) {
+            loop {
+                let arg = self.argument()?;
+                if let (Some(Arg::Named(name, _)), Arg::Positional(_) | Arg::Spread(..)) =
+                    (args.last(), &arg)
+                {
+                    return Err(RoxError::parse(&name.span, Code::PositionalAfterNamed));
+                }
+                args.push(arg);
+                if !self.matches(vec![TokenType::Comma]) {
+                    break;
+                }
+            }
+        }
+
+        let paren = self.consume(TokenType::RightParen, Code::MissingParen)?;
+        Ok(Expr::Call(Box::new(callee), paren, args))
+    }
+
+    fn argument(&mut self) -> Result<Arg, RoxError> {
+        if self.matches(vec![TokenType::Ellipsis]) {
+            Ok(Arg::Spread(self.previous(), self.expression()?))
+        } else if self.check(TokenType::Identifier) && self.check_next(TokenType::Colon) {
+            let name = self.advance();
+            self.advance();
+            Ok(Arg::Named(name, self.expression()?))
+        } else {
+            Ok(Arg::Positional(self.expression()?))
+        }
+    }
+
+    fn primary(&mut self) -> Result<Expr, RoxError> {
         if self.matches(vec![TokenType::True]) {
-            return Expr::Lit(Literal::True);
+            return Ok(Expr::Lit(Literal::True));
         } else if self.matches(vec![TokenType::False]) {
-            return Expr::Lit(Literal::False);
+            return Ok(Expr::Lit(Literal::False));
         } else if self.matches(vec![TokenType::Nil]) {
-            return Expr::Lit(Literal::Nil);
+            return Ok(Expr::Lit(Literal::Nil));
         }
 
         if self.matches(vec![TokenType::Identifier]) {
-            return Expr::Var(self.previous());
+            return Ok(Expr::Var(Variable::new(self.previous())));
+        }
+
+        if self.matches(vec![TokenType::This]) {
+            if self.class_depth == 0 {
+                return Err(RoxError::parse(
+                    &self.previous().span,
+                    Code::ThisOutsideClass,
+                ));
+            }
+            return Ok(Expr::This(Variable::new(self.previous())));
         }
 
         if self.matches(vec![TokenType::Number, TokenType::String]) {
-            return Expr::Lit(self.previous().literal);
+            return Ok(Expr::Lit(self.previous().literal));
+        }
+
+        if self.matches(vec![TokenType::Fun]) {
+            return self.lambda();
+        }
+
+        if self.check(TokenType::LeftParen) && self.is_arrow_function() {
+            self.advance();
+            return self.arrow_function();
+        }
+
+        if self.matches(vec![TokenType::LeftBracket]) {
+            return self.list();
+        }
+
+        if self.matches(vec![TokenType::LeftBrace]) {
+            return self.map();
         }
 
         if self.matches(vec![TokenType::LeftParen]) {
-            let expr = self.expression();
-            self.consume(
-                TokenType::RightParen,
-                "Je bent de ')' vergeten (je mag niet meer op mijn kinderfeestje komen)",
-            );
+            // () is the empty tuple, and the comma in (x,) makes it a tuple instead of a grouping
+            if self.matches(vec![TokenType::RightParen]) {
+                return Ok(Expr::Tuple(Vec::new()));
+            }
+            let expr = self.expression()?;
+
+            if self.matches(vec![TokenType::Comma]) {
+                let mut exprs = vec![expr];
+                while !self.check(TokenType::RightParen) {
+                    exprs.push(self.expression()?);
+                    if !self.matches(vec![TokenType::Comma]) {
+                        break;
+                    }
+                }
+                self.consume(TokenType::RightParen, Code::MissingParen)?;
+                return Ok(Expr::Tuple(exprs));
+            }
 
-            return Expr::Grouping(Box::new(expr));
+            self.consume(TokenType::RightParen, Code::UnclosedGroup)?;
+
+            return Ok(Expr::Grouping(Box::new(expr)));
+        }
+
+        if self.check(TokenType::Yield) {
+            return Err(RoxError::parse(&self.peek().span, Code::YieldPosition));
         }
 
-        let str = format!("{:?} past hier niet oelewapper.", self.peek().kind);
-        crash(self.peek().line, &str);
+        let kind = format!("{:?}", self.peek().kind);
+        Err(RoxError::parse(
+            &self.peek().span,
+            Code::UnexpectedToken.with(&[&kind]),
+        ))
+    }
+
+    fn list(&mut self) -> Result<Expr, RoxError> {
+        let mut exprs = Vec::new();
+        if !self.check(TokenType::RightBracket) {
+            exprs.push(self.expression()?);
+
+            if self.check(TokenType::For) {
+                let clauses = self.clauses()?;
+                self.consume(TokenType::RightBracket, Code::MissingBracket)?;
+                return Ok(Expr::Comprehension(Box::new(exprs.remove(0)), clauses));
+            }
+
+            while self.matches(vec![TokenType::Comma]) {
+                exprs.push(self.expression()?);
+            }
+        }
+        self.consume(TokenType::RightBracket, Code::MissingBracket)?;
+        Ok(Expr::List(exprs))
     }
 
-    fn consume(&mut self, token_type: TokenType, msg: &str) -> Token {
+    fn map(&mut self) -> Result<Expr, RoxError> {
+        let mut pairs = Vec::new();
+        if !self.check(TokenType::RightBrace) {
+            loop {
+                let key = self.expression()?;
+                self.consume(TokenType::Colon, Code::ExpectedColonAfterKey)?;
+                let value = self.expression()?;
+
+                if pairs.is_empty() && self.check(TokenType::For) {
+                    let clauses = self.clauses()?;
+                    self.consume(TokenType::RightBrace, Code::MissingBrace)?;
+                    return Ok(Expr::MapComprehension(
+                        Box::new(key),
+                        Box::new(value),
+                        clauses,
+                    ));
+                }
+
+                pairs.push((key, value));
+                if !self.matches(vec![TokenType::Comma]) {
+                    break;
+                }
+            }
+        }
+        self.consume(TokenType::RightBrace, Code::MissingBrace)?;
+        Ok(Expr::Map(pairs))
+    }
+
+    // voor x in lijst als x > 3 voor y in 0 tot x ...
+    fn clauses(&mut self) -> Result<Vec<Clause>, RoxError> {
+        let mut clauses = Vec::new();
+        loop {
+            if self.matches(vec![TokenType::For]) {
+                let pattern = self.pattern()?;
+                self.consume(TokenType::In, Code::ExpectedInAfterName)?;
+                let start = self.or()?;
+                let mut end = None;
+                if self.matches(vec![TokenType::Until]) {
+                    end = Some(self.or()?);
+                }
+                clauses.push(Clause::For(pattern, start, end));
+            } else if self.matches(vec![TokenType::If]) {
+                let keyword = self.previous();
+                clauses.push(Clause::If(keyword, self.or()?));
+            } else {
+                break;
+            }
+        }
+        Ok(clauses)
+    }
+
+    fn consume(&mut self, token_type: TokenType, msg: Code) -> Result<Token, RoxError> {
         if self.check(token_type) {
-            self.advance()
+            Ok(self.advance())
+        } else if token_type == TokenType::Semicolon && self.current > 0 {
+            // a missing ';' belongs at the end of the previous token, not on the next line
+            Err(RoxError::parse(self.previous().span.after(), msg))
         } else {
-            crash(self.peek().line, msg);
+            Err(RoxError::parse(&self.peek().span, msg))
         }
     }
 
     fn matches(&mut self, t: Vec<TokenType>) -> bool {
-        for i in 0..t.len() {
-            if self.check(t[i]) {
+        for kind in t {
+            if self.check(kind) {
                 self.advance();
                 return true;
             }
@@ -312,6 +1056,13 @@ impl Parser {
         }
     }
 
+    fn check_next(&self, kind: TokenType) -> bool {
+        match self.tokens.get(self.current + 1) {
+            Some(token) => token.kind == kind,
+            None => false,
+        }
+    }
+
     fn advance(&mut self) -> Token {
         if !self.is_at_end() {
             self.current += 1;
@@ -320,7 +1071,7 @@ impl Parser {
     }
 
     fn is_at_end(&self) -> bool {
-        self.peek().kind == TokenType::EOF
+        self.peek().kind == TokenType::Eof
     }
 
     fn peek(&self) -> Token {
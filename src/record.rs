@@ -0,0 +1,31 @@
+use std::rc::Rc;
+
+use crate::value::Value;
+
+// what is declared with 'record Punt(x, y);', calling it creates a record
+#[derive(Debug)]
+pub struct RecordType {
+    pub name: String,
+    pub fields: Vec<String>,
+}
+impl RecordType {
+    pub fn new(name: String, fields: Vec<String>) -> Self {
+        Self { name, fields }
+    }
+}
+
+#[derive(Debug)]
+pub struct Record {
+    pub kind: Rc<RecordType>,
+    pub values: Vec<Value>,
+}
+impl Record {
+    pub fn new(kind: Rc<RecordType>, values: Vec<Value>) -> Self {
+        Self { kind, values }
+    }
+
+    pub fn get(&self, field: &str) -> Option<&Value> {
+        let i = self.kind.fields.iter().position(|f| f == field)?;
+        self.values.get(i)
+    }
+}
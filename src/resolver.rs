@@ -0,0 +1,613 @@
+use std::{
+    collections::{HashMap, HashSet},
+    slice,
+};
+
+use crate::{
+    error::{ErrorKind, RoxError, Span},
+    expr::{Clause, Expr, Place, Variable},
+    messages::{Code, Text},
+    natives,
+    stmt::{Class, Fun, Stmt, Trait},
+    suggest,
+    token::{Literal, Token},
+    token_type::TokenType,
+};
+
+#[derive(Debug, Clone)]
+enum Binding {
+    Mutable,
+    // where it was made 'vast', to point at it when it is changed anyway
+    Const(Span),
+}
+
+// what made the name, only variables and loop variables warn when they are never used
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Origin {
+    Variable,
+    Loop,
+    Other,
+}
+
+#[derive(Clone)]
+struct Declaration {
+    binding: Binding,
+    origin: Origin,
+    name: Token,
+    // the place in its scope, the same one the interpreter puts the value in
+    slot: usize,
+    used: bool,
+    // false while the value of a 'laat' in a block is worked out
+    ready: bool,
+    // how many processen deep it was declared
+    function: usize,
+}
+
+// a variable used before its name was declared, a proces can use a global that comes after it
+struct Undeclared<'a> {
+    variable: &'a Variable,
+    assigned: bool,
+    // outside every proces, so it runs before the declaration does
+    early: bool,
+    // what could be seen from there, for the suggestions
+    visible: Vec<String>,
+}
+
+// walks through the program before it is run, so mistakes are also found in code that never runs
+// and every variable is told where it lives, so the interpreter doesn't have to search for it
+pub struct Resolver<'a> {
+    // the first scope holds the globals, including the built-in functions
+    scopes: Vec<HashMap<String, Declaration>>,
+    functions: usize,
+    // the names of those variables, so they don't warn about being unused
+    unresolved: HashSet<String>,
+    // checked and placed when the whole file is read
+    undeclared: Vec<Undeclared<'a>>,
+    // the eigenschappen seen so far, so a klas that misses one of their methods is found before running
+    traits: Vec<&'a Trait>,
+    warnings: Vec<RoxError>,
+}
+impl<'a> Resolver<'a> {
+    pub fn new() -> Self {
+        let mut resolver = Self {
+            scopes: vec![HashMap::new()],
+            functions: 0,
+            unresolved: HashSet::new(),
+            undeclared: Vec::new(),
+            traits: Vec::new(),
+            warnings: Vec::new(),
+        };
+        for native in natives::globals() {
+            let name = Token::new(
+                TokenType::Identifier,
+                native.name.to_string(),
+                Literal::None,
+                Span::default(),
+            );
+            resolver
+                .declare(&name, Binding::Mutable, Origin::Other)
+                .expect("a native is never 'vast'");
+        }
+        resolver
+    }
+
+    pub fn resolve(&mut self, statements: &'a [Stmt]) -> Result<(), RoxError> {
+        self.resolve_stmts(statements)?;
+        self.check_undeclared()
+    }
+
+    // for the prompt, which only has one expression
+    pub fn resolve_expression(&mut self, expr: &'a Expr) -> Result<(), RoxError> {
+        self.resolve_expr(expr)?;
+        self.check_undeclared()
+    }
+
+    fn resolve_stmts(&mut self, statements: &'a [Stmt]) -> Result<(), RoxError> {
+        for (i, stmt) in statements.iter().enumerate() {
+            self.resolve_stmt(stmt)?;
+            if let Stmt::Return(keyword, _) = stmt {
+                if i + 1 < statements.len() {
+                    let warning = RoxError::warning(&keyword.span, Code::UnreachableAfterReturn);
+                    self.warnings.push(warning);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // the warnings of the whole program, in the order of the code
+    pub fn warnings(mut self) -> Vec<RoxError> {
+        while !self.scopes.is_empty() {
+            self.end_scope();
+        }
+        self.warnings
+            .sort_by_key(|warning| (warning.span.line, warning.span.column));
+        self.warnings
+    }
+
+    fn resolve_stmt(&mut self, stmt: &'a Stmt) -> Result<(), RoxError> {
+        match stmt {
+            Stmt::Expr(expr) | Stmt::Print(expr) | Stmt::Println(expr) | Stmt::Return(_, expr) => {
+                self.resolve_expr(expr)?
+            }
+
+            Stmt::Var(name, expr) => {
+                self.resolve_declaration(slice::from_ref(name), expr, false)?
+            }
+            Stmt::Const(name, expr) => {
+                self.resolve_declaration(slice::from_ref(name), expr, true)?
+            }
+            Stmt::Destructure(pattern, expr) => {
+                self.resolve_declaration(&pattern.names(), expr, false)?
+            }
+            Stmt::MultiAssign(names, _, values) => {
+                for value in values {
+                    self.resolve_expr(value)?;
+                }
+                for name in names {
+                    self.check_assign(name)?;
+                }
+            }
+
+            Stmt::Block(statements) if !statements.iter().any(Stmt::declares) => {
+                self.resolve_stmts(statements)?
+            }
+            Stmt::Block(statements) => {
+                self.begin_scope();
+                self.resolve_stmts(statements)?;
+                self.end_scope();
+            }
+
+            Stmt::If(first_if, else_ifs, other) => {
+                for i in std::iter::once(first_if).chain(else_ifs) {
+                    self.resolve_expr(&i.should_execute)?;
+                    self.resolve_stmt(&i.statement)?;
+                }
+                if let Some(other) = other {
+                    self.resolve_stmt(other)?;
+                }
+            }
+
+            Stmt::While(keyword, expr, statement) => {
+                self.resolve_expr(expr)?;
+                self.check_loop_body(keyword, statement);
+                self.resolve_stmt(statement)?;
+            }
+
+            Stmt::For(name, start, end, statement) => {
+                self.resolve_expr(start)?;
+                self.resolve_expr(end)?;
+                self.begin_scope();
+                self.declare(name, Binding::Mutable, Origin::Loop)?;
+                self.check_loop_body(name, statement);
+                self.resolve_stmt(statement)?;
+                self.end_scope();
+            }
+
+            Stmt::ForIn(pattern, keyword, iterable, statement) => {
+                self.resolve_expr(iterable)?;
+                self.begin_scope();
+                for name in pattern.names() {
+                    self.declare(&name, Binding::Mutable, Origin::Loop)?;
+                }
+                self.check_loop_body(keyword, statement);
+                self.resolve_stmt(statement)?;
+                self.end_scope();
+            }
+
+            Stmt::Fun(fun) => {
+                self.declare(&fun.name, Binding::Mutable, Origin::Other)?;
+                self.resolve_function(fun)?;
+            }
+
+            Stmt::Record(name, _) => self.declare(name, Binding::Mutable, Origin::Other)?,
+            Stmt::Class(class) => {
+                for name in &class.traits {
+                    self.use_variable(name)?;
+                }
+                self.check_trait_methods(class)?;
+                self.declare(&class.name, Binding::Mutable, Origin::Other)?;
+                for method in &class.methods {
+                    self.resolve_method(method)?;
+                }
+            }
+            Stmt::Trait(trait_decl) => {
+                self.declare(&trait_decl.name, Binding::Mutable, Origin::Other)?;
+                self.traits.push(trait_decl);
+                for method in &trait_decl.methods {
+                    self.resolve_method(method)?;
+                }
+            }
+            Stmt::Import(import) => self.declare(&import.name, Binding::Mutable, Origin::Other)?,
+            Stmt::Export(_, stmt) => {
+                self.resolve_stmt(stmt)?;
+                // another file can use it, so it is never unused
+                if let Stmt::Var(name, _) | Stmt::Const(name, _) = &**stmt {
+                    if let Some(declaration) = self.scopes[0].get_mut(&name.lexeme) {
+                        declaration.used = true;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // only for the eigenschappen the name still points at here, the interpreter checks the others
+    fn check_trait_methods(&self, class: &Class) -> Result<(), RoxError> {
+        for variable in &class.traits {
+            let name = &variable.name;
+            let found = (self.scopes.iter().rev()).find_map(|scope| scope.get(&name.lexeme));
+            let Some(declaration) = found else {
+                continue;
+            };
+            let offset = declaration.name.span.offset;
+            let Some(trait_decl) = (self.traits.iter()).find(|t| t.name.span.offset == offset)
+            else {
+                continue;
+            };
+            for required in &trait_decl.required {
+                if !class
+                    .methods
+                    .iter()
+                    .any(|m| m.name.lexeme == required.lexeme)
+                {
+                    let msg = Code::MissingTraitMethod.with(&[
+                        &class.name.lexeme,
+                        &trait_decl.name.lexeme,
+                        &required.lexeme,
+                    ]);
+                    return Err(RoxError::resolve(ErrorKind::Type, &class.name.span, msg));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn check_loop_body(&mut self, keyword: &Token, statement: &Stmt) {
+        if matches!(statement, Stmt::Block(statements) if statements.is_empty()) {
+            let warning = RoxError::warning(&keyword.span, Code::EmptyLoopBody);
+            self.warnings.push(warning);
+        }
+    }
+
+    // a 'laat' in a block is declared before its value, so a proces in the value can call itself,
+    // a global keeps its old value until the new one is there, like the interpreter does
+    fn resolve_declaration(
+        &mut self,
+        names: &[Token],
+        value: &'a Expr,
+        constant: bool,
+    ) -> Result<(), RoxError> {
+        let global = self.scopes.len() == 1;
+        if global {
+            self.resolve_expr(value)?;
+        }
+        for name in names {
+            let binding = match constant {
+                true => Binding::Const(name.span.clone()),
+                false => Binding::Mutable,
+            };
+            self.declare(name, binding, Origin::Variable)?;
+            self.set_ready(name, global);
+        }
+        if !global {
+            self.resolve_expr(value)?;
+            for name in names {
+                self.set_ready(name, true);
+            }
+        }
+        Ok(())
+    }
+
+    fn set_ready(&mut self, name: &Token, ready: bool) {
+        if let Some(declaration) = self.scopes.last_mut().and_then(|s| s.get_mut(&name.lexeme)) {
+            declaration.ready = ready;
+        }
+    }
+
+    fn resolve_function(&mut self, fun: &'a Fun) -> Result<(), RoxError> {
+        self.functions += 1;
+        self.begin_scope();
+        for param in &fun.params {
+            if let Some(default) = &param.default {
+                self.resolve_expr(default)?;
+            }
+            self.declare(&param.name, Binding::Mutable, Origin::Other)?;
+        }
+        self.resolve_stmts(&fun.body)?;
+        self.end_scope();
+        self.functions -= 1;
+        Ok(())
+    }
+
+    // a method gets a scope with 'dit' around it when it is taken from an object
+    fn resolve_method(&mut self, method: &'a Fun) -> Result<(), RoxError> {
+        let mut dit = method.name.clone();
+        dit.rename("dit".to_string());
+        self.begin_scope();
+        self.declare(&dit, Binding::Mutable, Origin::Other)?;
+        self.resolve_function(method)?;
+        self.end_scope();
+        Ok(())
+    }
+
+    fn resolve_expr(&mut self, expr: &'a Expr) -> Result<(), RoxError> {
+        match expr {
+            Expr::Lit(_) => (),
+            Expr::Var(variable) | Expr::This(variable) => self.use_variable(variable)?,
+            Expr::Grouping(expr)
+            | Expr::Unary(_, expr)
+            | Expr::Try(expr, _)
+            | Expr::Yield(_, expr) => self.resolve_expr(expr)?,
+            Expr::Get(expr, _) => self.resolve_expr(expr)?,
+            Expr::Set(object, _, value) => {
+                self.resolve_expr(object)?;
+                self.resolve_expr(value)?;
+            }
+            Expr::Lambda(fun) => self.resolve_function(fun)?,
+            Expr::Binary(left, op, right) if op.kind == TokenType::EqualEqual => {
+                if let (Some(kind1), Some(kind2)) = (kind_of(left), kind_of(right)) {
+                    if kind1 != kind2 {
+                        let msg = Code::AlwaysFalseComparison.with(&[&kind1, &kind2]);
+                        self.warnings.push(RoxError::warning(&op.span, msg));
+                    }
+                }
+                self.resolve_expr(left)?;
+                self.resolve_expr(right)?;
+            }
+            Expr::Binary(left, _, right)
+            | Expr::Logic(left, _, right)
+            | Expr::Pipe(left, _, right) => {
+                self.resolve_expr(left)?;
+                self.resolve_expr(right)?;
+            }
+            Expr::Call(callee, _, args) => {
+                self.resolve_expr(callee)?;
+                for arg in args {
+                    self.resolve_expr(arg.expr())?;
+                }
+            }
+            Expr::List(exprs) | Expr::Tuple(exprs) => {
+                for expr in exprs {
+                    self.resolve_expr(expr)?;
+                }
+            }
+            Expr::Map(pairs) => {
+                for (key, value) in pairs {
+                    self.resolve_expr(key)?;
+                    self.resolve_expr(value)?;
+                }
+            }
+            Expr::Comprehension(expr, clauses) => {
+                self.begin_scope();
+                self.resolve_clauses(clauses)?;
+                self.resolve_expr(expr)?;
+                self.end_scope();
+            }
+            Expr::MapComprehension(key, value, clauses) => {
+                self.begin_scope();
+                self.resolve_clauses(clauses)?;
+                self.resolve_expr(key)?;
+                self.resolve_expr(value)?;
+                self.end_scope();
+            }
+            Expr::Index(list, _, index) => {
+                self.resolve_expr(list)?;
+                self.resolve_expr(index)?;
+            }
+            Expr::SetIndex(list, _, index, value) => {
+                self.resolve_expr(list)?;
+                self.resolve_expr(index)?;
+                self.resolve_expr(value)?;
+            }
+            Expr::Assign(variable, value) => {
+                self.resolve_expr(value)?;
+                self.check_assign(variable)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn resolve_clauses(&mut self, clauses: &'a [Clause]) -> Result<(), RoxError> {
+        for clause in clauses {
+            match clause {
+                Clause::For(pattern, start, end) => {
+                    self.resolve_expr(start)?;
+                    if let Some(end) = end {
+                        self.resolve_expr(end)?;
+                    }
+                    for name in pattern.names() {
+                        self.declare(&name, Binding::Mutable, Origin::Other)?;
+                    }
+                }
+                Clause::If(_, expr) => self.resolve_expr(expr)?,
+            }
+        }
+        Ok(())
+    }
+
+    fn check_assign(&mut self, variable: &'a Variable) -> Result<(), RoxError> {
+        let name = &variable.name;
+        if let Some(Binding::Const(declared)) = self.lookup(name) {
+            return Err(const_assign(name, &declared));
+        }
+        self.place(variable, true);
+        Ok(())
+    }
+
+    fn declare(&mut self, name: &Token, binding: Binding, origin: Origin) -> Result<(), RoxError> {
+        if origin == Origin::Variable {
+            self.check_shadowed_loop(name);
+        }
+        let function = self.functions;
+        let Some(scope) = self.scopes.last_mut() else {
+            return Ok(());
+        };
+        // a 'vast' can't be changed by declaring it again either
+        if let Some(Declaration {
+            binding: Binding::Const(declared),
+            ..
+        }) = scope.get(&name.lexeme)
+        {
+            return Err(const_assign(name, declared));
+        }
+        // 'laat' again in the same scope replaces the old one, which can't be used anymore
+        let slot = scope.get(&name.lexeme).map_or(scope.len(), |old| old.slot);
+        let declaration = Declaration {
+            binding,
+            origin,
+            name: name.clone(),
+            slot,
+            used: false,
+            ready: true,
+            function,
+        };
+        if let Some(old) = scope.insert(name.lexeme.clone(), declaration) {
+            self.check_unused(old);
+        }
+        Ok(())
+    }
+
+    // voor i van 0 tot 10 { laat i = 3; }
+    fn check_shadowed_loop(&mut self, name: &Token) {
+        let mut outer = self.scopes.iter().rev().skip(1);
+        let Some(loop_var) = outer.find_map(|scope| scope.get(&name.lexeme)) else {
+            return;
+        };
+        if loop_var.origin == Origin::Loop {
+            let msg = Code::ShadowedLoopVariable.with(&[&name.lexeme]);
+            let warning = RoxError::warning(&name.span, msg)
+                .with_label(&loop_var.name.span, Text::LoopVariableHere.to_string());
+            self.warnings.push(warning);
+        }
+    }
+
+    fn use_variable(&mut self, variable: &'a Variable) -> Result<(), RoxError> {
+        let name = &variable.name;
+        let functions = self.functions;
+        let found = (self.scopes.iter_mut().rev()).find_map(|scope| scope.get_mut(&name.lexeme));
+        if let Some(declaration) = found {
+            // inside a proces it is only read later, when the value is long done
+            if !declaration.ready && declaration.function == functions {
+                let msg = Code::ReadInOwnInitializer.with(&[&name.lexeme]);
+                return Err(RoxError::resolve(ErrorKind::Name, &name.span, msg));
+            }
+            declaration.used = true;
+        }
+        self.place(variable, false);
+        Ok(())
+    }
+
+    // tells the variable where it lives, a name that isn't found yet has to be a global
+    fn place(&mut self, variable: &'a Variable, assigned: bool) {
+        let name = &variable.name;
+        let found = (self.scopes.iter().enumerate().rev())
+            .find_map(|(i, scope)| Some((i, scope.get(&name.lexeme)?.slot)));
+        match found {
+            Some((0, slot)) => variable.place.set(Place::Global(slot)),
+            Some((i, slot)) => variable.place.set(Place::Local {
+                depth: self.scopes.len() - 1 - i,
+                slot,
+            }),
+            None => {
+                self.unresolved.insert(name.lexeme.clone());
+                let visible = self.scopes.iter().flat_map(|scope| scope.keys().cloned());
+                self.undeclared.push(Undeclared {
+                    variable,
+                    assigned,
+                    early: self.functions == 0,
+                    visible: visible.collect(),
+                });
+            }
+        }
+    }
+
+    // a global can be declared after the proces that uses it, so this waits for the whole file,
+    // code outside a proces runs in order and can't use it before its declaration
+    fn check_undeclared(&mut self) -> Result<(), RoxError> {
+        for undeclared in self.undeclared.drain(..) {
+            let name = &undeclared.variable.name;
+            match self.scopes[0].get(&name.lexeme) {
+                Some(declaration) if undeclared.early => {
+                    let msg = Code::UnknownVariable.with(&[&name.lexeme]);
+                    let error = RoxError::resolve(ErrorKind::Name, &name.span, msg);
+                    let label = Text::DeclaredLaterHere.to_string();
+                    return Err(error.with_label(&declaration.name.span, label));
+                }
+                Some(declaration) => match &declaration.binding {
+                    Binding::Const(declared) if undeclared.assigned => {
+                        return Err(const_assign(name, declared));
+                    }
+                    _ => (undeclared.variable.place).set(Place::Global(declaration.slot)),
+                },
+                None => {
+                    let msg = Code::UnknownVariable.with(&[&name.lexeme]);
+                    let globals = self.scopes[0].keys().map(String::as_str);
+                    let visible = undeclared.visible.iter().map(String::as_str);
+                    let close = suggest::closest(&name.lexeme, visible.chain(globals));
+                    let error = RoxError::resolve(ErrorKind::Name, &name.span, msg);
+                    return Err(error.with_help(suggest::did_you_mean(&close)));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn check_unused(&mut self, declaration: Declaration) {
+        let name = &declaration.name.lexeme;
+        if declaration.used
+            || declaration.origin == Origin::Other
+            || name.starts_with('_')
+            || self.unresolved.contains(name)
+        {
+            return;
+        }
+        let msg = Code::UnusedVariable.with(&[name]);
+        let warning = RoxError::warning(&declaration.name.span, msg)
+            .with_help(Some(Text::UnusedHelp.to_string()));
+        self.warnings.push(warning);
+    }
+
+    fn lookup(&self, name: &Token) -> Option<Binding> {
+        self.scopes
+            .iter()
+            .rev()
+            .find_map(|scope| Some(scope.get(&name.lexeme)?.binding.clone()))
+    }
+
+    fn begin_scope(&mut self) {
+        self.scopes.push(HashMap::new());
+    }
+
+    fn end_scope(&mut self) {
+        if let Some(scope) = self.scopes.pop() {
+            let mut declarations: Vec<Declaration> = scope.into_values().collect();
+            declarations.sort_by_key(|declaration| declaration.slot);
+            for declaration in declarations {
+                self.check_unused(declaration);
+            }
+        }
+    }
+}
+
+fn const_assign(name: &Token, declared: &Span) -> RoxError {
+    let msg = Code::ConstAssign.with(&[&name.lexeme]);
+    let error = RoxError::resolve(ErrorKind::Const, &name.span, msg);
+    error.with_label(declared, Text::DeclaredConstHere.to_string())
+}
+
+// the kind of value an expression always has, when that can be seen without running it
+fn kind_of(expr: &Expr) -> Option<Text> {
+    match expr {
+        Expr::Lit(Literal::Num(_)) => Some(Text::Number),
+        Expr::Lit(Literal::Str(_)) => Some(Text::String),
+        Expr::Lit(Literal::True | Literal::False) => Some(Text::Bool),
+        Expr::Lit(Literal::Nil) => Some(Text::Nil),
+        Expr::List(_) | Expr::Comprehension(..) => Some(Text::List),
+        Expr::Tuple(_) => Some(Text::Tuple),
+        Expr::Map(_) | Expr::MapComprehension(..) => Some(Text::Map),
+        Expr::Lambda(_) => Some(Text::Proces),
+        Expr::Grouping(expr) => kind_of(expr),
+        _ => None,
+    }
+}
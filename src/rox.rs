@@ -1,6 +1,14 @@
-use std::fs;
+use std::{fs, path::PathBuf};
 
-use crate::{interpreter::Interpreter, parser::Parser, scanner::Scanner};
+use crate::{
+    error::{self, ErrorKind, Phase, RoxError},
+    interpreter::{Interpreter, Unwind},
+    lint,
+    messages::Code,
+    parser::{Parser, MAX_ERRORS},
+    resolver::Resolver,
+    scanner::Scanner,
+};
 
 const PRINT_SCAN_OUTPUT: bool = false;
 const PRINT_PARS_OUTPUT: bool = false;
@@ -8,50 +16,97 @@ const PRINT_PARS_OUTPUT: bool = false;
 
 pub struct Rox {
     //had_error: bool,
+    // how many syntax errors are shown before giving up
+    pub max_errors: usize,
+    // makes every warning an error, unless a pragma in the file says otherwise
+    pub deny_warnings: bool,
 }
 impl Rox {
     pub fn new() -> Self {
         //Self { had_error: false }
-        Self {}
+        Self {
+            max_errors: MAX_ERRORS,
+            deny_warnings: false,
+        }
     }
 
-    pub fn run_prompt(&mut self, source: String) {
-        let mut scanner = Scanner::new(source);
-        let tokens = scanner.scan_tokens();
+    pub fn run_prompt(&mut self, source: String) -> Result<(), Vec<RoxError>> {
+        let mut scanner = Scanner::new(source, None);
+        let tokens = scanner.scan_tokens()?;
 
         let mut parser = Parser::new(tokens);
-        let expr = parser.parse_expr();
+        let expr = parser.parse_expr().map_err(|error| vec![error])?;
         if PRINT_PARS_OUTPUT {
-            println!("{}", expr.to_string());
+            println!("{}", expr);
         }
+        Resolver::new()
+            .resolve_expression(&expr)
+            .map_err(|error| vec![error])?;
 
-        let mut interpreter = Interpreter::new();
-        let value = interpreter.evaluate_expr(&expr);
-
-        println!("{}", value.to_string());
+        let mut interpreter = Interpreter::new(None);
+        match interpreter.evaluate_expr(&expr) {
+            Ok(value) => println!("{}", value),
+            Err(Unwind::Error(error)) => return Err(vec![error]),
+            Err(Unwind::Return(_) | Unwind::TailCall(_)) => panic!("Unreachable."),
+        }
+        Ok(())
     }
 
-    pub fn run_file(&mut self, source: &str) {
-        let source = fs::read_to_string(source).expect("file.lox is niet gevonden. het moet in dezelfde directory als de binary of Cargo.toml zitten.");
-        let source = source.to_string();
-        self.run(source);
+    pub fn run_file(&mut self, path: &str) -> Result<(), Vec<RoxError>> {
+        let source = match fs::read_to_string(path) {
+            Ok(source) => source,
+            Err(_) => {
+                let msg = Code::FileNotFound.with(&[&path]);
+                return Err(vec![RoxError::new(ErrorKind::Io, Phase::Scan, 0, msg)]);
+            }
+        };
+        self.run(source, PathBuf::from(path))
     }
 
-    fn run(&mut self, source: String) {
-        let mut scanner = Scanner::new(source);
+    fn run(&mut self, source: String, path: PathBuf) -> Result<(), Vec<RoxError>> {
+        let mut scanner = Scanner::new(source, Some(path.clone()));
 
-        let tokens = scanner.scan_tokens();
+        let (tokens, mut errors) = scanner.scan_all();
         if PRINT_SCAN_OUTPUT {
             for token in &tokens {
-                print!("{}_", token.to_string());
+                print!("{}_", token);
             }
             println!();
         }
 
         let mut parser = Parser::new(tokens);
-        let statements = parser.parse_statements();
+        parser.max_errors = self.max_errors;
+        // the errors of the scanner and the parser are shown together, in the order of the file
+        let statements = match parser.parse_statements() {
+            Ok(statements) => statements,
+            // on a line with a wrong character the parse errors usually come from skipping it
+            Err(parse_errors) => {
+                let lines: Vec<u32> = errors.iter().map(|error| error.span.line).collect();
+                let parse_errors = parse_errors.into_iter();
+                errors.extend(parse_errors.filter(|error| !lines.contains(&error.span.line)));
+                Vec::new()
+            }
+        };
+        if !errors.is_empty() {
+            errors.sort_by_key(|error| (error.span.line, error.span.column));
+            errors.truncate(self.max_errors);
+            return Err(errors);
+        }
+
+        let mut resolver = Resolver::new();
+        resolver.resolve(&statements).map_err(|error| vec![error])?;
+
+        // the warnings come before anything the program prints
+        let (warnings, denied) =
+            lint::sort(resolver.warnings(), &scanner.pragmas, self.deny_warnings);
+        error::warn(&warnings);
+        if !denied.is_empty() {
+            return Err(denied);
+        }
 
-        let mut interpreter = Interpreter::new();
-        interpreter.interpret(statements);
+        let mut interpreter = Interpreter::new(Some(path));
+        interpreter
+            .interpret(statements)
+            .map_err(|error| vec![error])
     }
 }
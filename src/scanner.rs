@@ -1,67 +1,110 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, path::PathBuf, rc::Rc};
 
-use crate::crash;
+use crate::error::{RoxError, Source, Span};
+use crate::lint::Pragma;
+use crate::messages::Code;
 use crate::token::{Literal, Token};
 use crate::token_type::TokenType;
 
+// the words that can't be used as names, also used to spot typos in them
+pub fn keywords() -> HashMap<String, TokenType> {
+    //let mut keywords = HashMap::new();
+    macro_rules! create_keywords {
+        ($($k: expr, $v: ident)*) => {
+            HashMap::from([
+                $(($k.to_string(), TokenType::$v),)*
+            ])
+        };
+    }
+
+    create_keywords!(
+        "en",And "of",Or "als",If "anders",Else "terwijl",While "voor",For "tot",Until
+        "wellus",True "nietus",False "niks",Nil "dit",This "ouder",Super "van",From
+        "klas",Class "proces",Fun "laat",Var "retour",Return "spreek",Print "roep", Println
+        "importeer",Import "exporteer",Export "vast",Const "record",Record "in",In
+        "lever",Yield "eigenschap",Trait "implementeert",Implements "is",Is
+    )
+}
+
 pub struct Scanner {
     source: String,
+    // the same code again, shared by the spans of all tokens
+    file: Rc<Source>,
     tokens: Vec<Token>,
     keywords: HashMap<String, TokenType>,
 
     start: usize,
     current: usize,
     line: usize,
+    // where the current line starts, to work out the column
+    line_start: usize,
+    // a string can span multiple lines, so its token starts on an earlier line
+    start_line: usize,
+    start_column: usize,
+    // the '// rox:' comments that allow or deny warnings in this file
+    pub pragmas: Vec<Pragma>,
 }
 
 impl Scanner {
-    pub fn new(source: String) -> Self {
-        //let mut keywords = HashMap::new();
-        macro_rules! create_keywords {
-            ($($k: expr, $v: ident)*) => {
-                HashMap::from([
-                    $(($k.to_string(), TokenType::$v),)*
-                ])
-            };
-        }
-
-        let keywords = create_keywords!(
-            "en",And "of",Or "als",If "anders",Else "terwijl",While "voor",For "tot",Until
-            "wellus",True "nietus",False "niks",Nil "dit",This "ouder",Super "van",From
-            "klas",Class "proces",Fun "laat",Var "retour",Return "spreek",Print "roep", Println
-        );
+    pub fn new(source: String, path: Option<PathBuf>) -> Self {
+        let keywords = keywords();
 
         Self {
+            file: Rc::new(Source::new(path, source.clone())),
             source,
             tokens: vec![],
             keywords,
             start: 0,
             current: 0,
             line: 1,
+            line_start: 0,
+            start_line: 1,
+            start_column: 1,
+            pragmas: Vec::new(),
+        }
+    }
+
+    // a wrong character is skipped, so all of them are found in one go
+    pub fn scan_tokens(&mut self) -> Result<Vec<Token>, Vec<RoxError>> {
+        let (tokens, errors) = self.scan_all();
+        if errors.is_empty() {
+            Ok(tokens)
+        } else {
+            Err(errors)
         }
     }
 
-    pub fn scan_tokens(&mut self) -> Vec<Token> {
+    // a wrong character is skipped, so the tokens can still be parsed to find the other errors
+    pub fn scan_all(&mut self) -> (Vec<Token>, Vec<RoxError>) {
+        let mut errors = Vec::new();
         while !self.at_end_input() {
             self.start = self.current;
-            self.scan_token();
+            self.start_line = self.line;
+            self.start_column = self.current - self.line_start + 1;
+            if let Err(error) = self.scan_token() {
+                errors.push(error);
+            }
         }
 
+        self.start = self.current;
+        self.start_line = self.line;
+        self.start_column = self.current - self.line_start + 1;
+        let span = self.span();
         self.tokens.push(Token::new(
-            TokenType::EOF,
+            TokenType::Eof,
             "".to_string(),
             Literal::None,
-            self.line,
+            span,
         ));
 
-        self.tokens.clone()
+        (self.tokens.clone(), errors)
     }
 
     fn at_end_input(&self) -> bool {
         self.current >= self.source.len()
     }
 
-    fn scan_token(&mut self) {
+    fn scan_token(&mut self) -> Result<(), RoxError> {
         let c = self.get_current_char();
         self.current += 1;
 
@@ -82,17 +125,43 @@ impl Scanner {
             ')' => self.add_token(TokenType::RightParen),
             '{' => self.add_token(TokenType::LeftBrace),
             '}' => self.add_token(TokenType::RightBrace),
+            '[' => self.add_token(TokenType::LeftBracket),
+            ']' => self.add_token(TokenType::RightBracket),
             ',' => self.add_token(TokenType::Comma),
-            '.' => self.add_token(TokenType::Dot),
+            ':' => self.add_token(TokenType::Colon),
+            '.' => {
+                if self.matches('.') && self.peek_next() == '.' {
+                    self.current += 2;
+                    self.add_token(TokenType::Ellipsis);
+                } else {
+                    self.add_token(TokenType::Dot);
+                }
+            }
             '-' => self.add_token(TokenType::Minus),
             '+' => self.add_token(TokenType::Plus),
             ';' => self.add_token(TokenType::Semicolon),
             '*' => self.add_token(TokenType::Star),
             '^' => self.add_token(TokenType::Caret),
+            '?' => self.add_token(TokenType::Question),
 
             '!' => ternary!('=', BangEqual, Bang),
-            '=' => ternary!('=', EqualEqual, Equal),
+            '=' => {
+                if self.matches('>') {
+                    self.current += 1;
+                    self.add_token(TokenType::Arrow);
+                } else {
+                    ternary!('=', EqualEqual, Equal)
+                }
+            }
             '<' => ternary!('=', LessEqual, Less),
+            '|' => {
+                if self.matches('>') {
+                    self.current += 1;
+                    self.add_token(TokenType::Pipe);
+                } else {
+                    return Err(RoxError::scan(self.span(), Code::ExpectedPipe));
+                }
+            }
             '>' => ternary!('=', GreaterEqual, Greater),
 
             // comments
@@ -101,6 +170,11 @@ impl Scanner {
                     while self.peek() != '\n' && !self.at_end_input() {
                         self.current += 1;
                     }
+                    let comment = &self.source[(self.start + 2)..self.current];
+                    if let Some(text) = comment.trim_start().strip_prefix("rox:") {
+                        let pragmas = Pragma::parse(text, &self.span())?;
+                        self.pragmas.extend(pragmas);
+                    }
                 } else if self.matches('*') {
                     self.check_for_end_comment();
                 } else {
@@ -113,12 +187,12 @@ impl Scanner {
             '"' => {
                 while self.peek() != '"' && !self.at_end_input() {
                     if self.peek() == '\n' {
-                        self.line += 1;
+                        self.new_line(self.current);
                     }
                     self.current += 1;
                 }
                 if self.at_end_input() {
-                    crash(self.line, "ongetermineerde reeks, appelflap");
+                    return Err(RoxError::scan(self.span(), Code::UnterminatedString));
                 }
 
                 self.current += 1;
@@ -130,13 +204,13 @@ impl Scanner {
             }
 
             ' ' | '\r' | '\t' => (),
-            '\n' => self.line += 1,
+            '\n' => self.new_line(self.start),
 
             _ => {
                 if c.is_ascii_digit() {
                     self.add_num_token()
                 } else if c.is_alphabetic() || c == '_' {
-                    while self.peek().is_alphanumeric() {
+                    while self.peek().is_alphanumeric() || self.peek() == '_' {
                         self.current += 1;
                     }
 
@@ -148,16 +222,17 @@ impl Scanner {
 
                     self.add_token(kind);
                 } else {
-                    crash(self.line, "Onverwacht karakter, dat kan beter appelflap");
+                    return Err(RoxError::scan(self.span(), Code::UnexpectedCharacter));
                 }
             }
         }
+        Ok(())
     }
 
     fn check_for_end_comment(&mut self) {
         while !self.at_end_input() {
             if self.peek() == '\n' {
-                self.line += 1;
+                self.new_line(self.current);
             }
             self.current += 1;
 
@@ -194,7 +269,25 @@ impl Scanner {
 
     fn add_lit_token(&mut self, kind: TokenType, lit: Literal) {
         let text = self.source[self.start..self.current].to_string();
-        self.tokens.push(Token::new(kind, text, lit, self.line));
+        let span = self.span();
+        self.tokens.push(Token::new(kind, text, lit, span));
+    }
+
+    // at is where the '\n' is
+    fn new_line(&mut self, at: usize) {
+        self.line += 1;
+        self.line_start = at + 1;
+    }
+
+    // the part of the source from self.start to self.current
+    fn span(&self) -> Span {
+        Span::new(
+            self.start_line,
+            self.start_column,
+            self.start,
+            self.current - self.start,
+            self.file.clone(),
+        )
     }
 
     fn add_token(&mut self, kind: TokenType) {
@@ -220,7 +313,7 @@ impl Scanner {
     }
 
     fn peek_next(&self) -> char {
-        if self.current >= self.source.len() {
+        if self.current + 1 >= self.source.len() {
             '\0'
         } else {
             self.get_next_char()
@@ -0,0 +1,263 @@
+use std::{
+    cell::RefCell,
+    collections::VecDeque,
+    mem,
+    rc::Rc,
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    error::RoxError,
+    generator::Generator,
+    interpreter::Interpreter,
+    messages::{Code, Message},
+    value::Value,
+};
+
+// a proces that runs next to the main program, it only gives the others a turn at a 'lever'
+#[derive(Debug)]
+pub struct Task {
+    pub name: String,
+    pub state: TaskState,
+}
+impl Task {
+    pub fn new(name: String, callee: Value, args: Vec<Value>) -> Self {
+        Self {
+            name,
+            state: TaskState::New(callee, args),
+        }
+    }
+
+    pub fn result(&self) -> Option<Result<Value, RoxError>> {
+        match &self.state {
+            TaskState::Done(value) => Some(Ok(value.clone())),
+            TaskState::Failed(error) => Some(Err(error.clone())),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum TaskState {
+    // the proces and its arguments, it is only called when the task gets its first turn
+    New(Value, Vec<Value>),
+    // the value is what the 'lever' the task is waiting at gives back
+    Running(Rc<RefCell<Generator>>, Value),
+    Done(Value),
+    // every wacht on the task gives this error again, not just the first one
+    Failed(RoxError),
+}
+
+#[derive(Debug, Default)]
+pub struct Channel {
+    pub values: VecDeque<Value>,
+}
+
+// what slaap(ms) and ontvang(kanaal) give back, a task waits for it with 'lever'
+#[derive(Debug)]
+pub enum Wait {
+    Sleep(f64),
+    Receive(Rc<RefCell<Channel>>),
+}
+
+// what a task, or the main program in wacht, is waiting for
+pub enum Blocked {
+    Task(Rc<RefCell<Task>>),
+    Time(f64),
+    Receive(Rc<RefCell<Channel>>),
+}
+impl Blocked {
+    // gives back the value the wait ends with once it is over, or the error of the task it waited for
+    fn poll(&self, now: f64) -> Option<Result<Value, RoxError>> {
+        match self {
+            Blocked::Task(task) => task.borrow().result(),
+            Blocked::Time(time) => (now >= *time).then_some(Ok(Value::Nil)),
+            Blocked::Receive(channel) => channel.borrow_mut().values.pop_front().map(Ok),
+        }
+    }
+}
+
+// in virtual mode time only moves when every task is waiting, so test runs are always the same
+pub struct Clock {
+    start: Instant,
+    virtual_time: Option<f64>,
+}
+impl Clock {
+    fn new() -> Self {
+        Self {
+            start: Instant::now(),
+            virtual_time: None,
+        }
+    }
+
+    // the milliseconds since the program started
+    pub fn now(&self) -> f64 {
+        match self.virtual_time {
+            Some(time) => time,
+            None => self.start.elapsed().as_secs_f64() * 1000.0,
+        }
+    }
+
+    fn advance_to(&mut self, time: f64) {
+        match &mut self.virtual_time {
+            Some(now) => *now = now.max(time),
+            None => {
+                let wait = time - self.now();
+                if wait > 0.0 {
+                    thread::sleep(Duration::from_secs_f64(wait / 1000.0));
+                }
+            }
+        }
+    }
+}
+
+// runs the tasks one by one, always in the same order
+pub struct Scheduler {
+    ready: VecDeque<Rc<RefCell<Task>>>,
+    waiting: Vec<(Rc<RefCell<Task>>, Blocked)>,
+    // the task that has the turn, it has to wait with 'lever' instead of with wacht
+    current: Option<Rc<RefCell<Task>>>,
+    pub clock: Clock,
+}
+impl Scheduler {
+    pub fn new() -> Self {
+        Self {
+            ready: VecDeque::new(),
+            waiting: Vec::new(),
+            current: None,
+            clock: Clock::new(),
+        }
+    }
+
+    pub fn spawn(&mut self, task: Rc<RefCell<Task>>) {
+        self.ready.push_back(task);
+    }
+
+    pub fn in_task(&self) -> bool {
+        self.current.is_some()
+    }
+
+    // the virtual clock starts at 0, so it is best switched on before any task sleeps
+    pub fn use_virtual_clock(&mut self) {
+        if self.clock.virtual_time.is_none() {
+            self.clock.virtual_time = Some(0.0);
+        }
+    }
+
+    // a task that gave its turn away with 'lever' waits for the value it gave
+    fn block(&mut self, task: Rc<RefCell<Task>>, value: Value) {
+        let blocked = match value {
+            Value::Task(other) => Blocked::Task(other),
+            Value::Wait(wait) => match &*wait {
+                Wait::Sleep(ms) => Blocked::Time(self.clock.now() + ms),
+                Wait::Receive(channel) => Blocked::Receive(channel.clone()),
+            },
+            // anything else only gives the other tasks a turn
+            _ => return self.ready.push_back(task),
+        };
+        self.waiting.push((task, blocked));
+    }
+
+    fn wake(&mut self) {
+        let now = self.clock.now();
+        let mut i = 0;
+        while i < self.waiting.len() {
+            match self.waiting[i].1.poll(now) {
+                Some(Ok(value)) => {
+                    let (task, _) = self.waiting.remove(i);
+                    if let TaskState::Running(_, sent) = &mut task.borrow_mut().state {
+                        *sent = value;
+                    }
+                    self.ready.push_back(task);
+                }
+                // a task that waits for a task that failed fails with the same error
+                Some(Err(error)) => {
+                    let (task, _) = self.waiting.remove(i);
+                    task.borrow_mut().state = TaskState::Failed(error);
+                }
+                None => i += 1,
+            }
+        }
+    }
+
+    // the first moment a task, or the main program, wakes up from slaap
+    fn next_time(&self, blocked: &Blocked) -> Option<f64> {
+        (self.waiting.iter().map(|(_, blocked)| blocked))
+            .chain(std::iter::once(blocked))
+            .filter_map(|blocked| match blocked {
+                Blocked::Time(time) => Some(*time),
+                _ => None,
+            })
+            .reduce(f64::min)
+    }
+}
+
+// lets the tasks run until the main program is done waiting, and gives back what it waited for
+pub fn run_until(interpreter: &mut Interpreter, blocked: Blocked) -> Result<Value, Message> {
+    loop {
+        let scheduler = interpreter.scheduler();
+        scheduler.wake();
+        match blocked.poll(scheduler.clock.now()) {
+            Some(Ok(value)) => return Ok(value),
+            Some(Err(error)) => return Err(interpreter.hand_to_native(error)),
+            None => (),
+        }
+
+        let scheduler = interpreter.scheduler();
+        match scheduler.ready.pop_front() {
+            Some(task) => step(interpreter, task),
+            // every task is waiting, so time can jump forward to the first one that wakes up
+            None => match scheduler.next_time(&blocked) {
+                Some(time) => scheduler.clock.advance_to(time),
+                None => return Err(Code::Deadlock.into()),
+            },
+        }
+    }
+}
+
+// gives the task a turn, until its next 'lever'
+fn step(interpreter: &mut Interpreter, task: Rc<RefCell<Task>>) {
+    let state = mem::replace(&mut task.borrow_mut().state, TaskState::Done(Value::Nil));
+    interpreter.scheduler().current = Some(task.clone());
+    let result = run_task(interpreter, state);
+    interpreter.scheduler().current = None;
+
+    let state = match result {
+        Ok(state) => state,
+        // the task keeps its error, only a wacht on this task gives it, not the one that happens to be running
+        Err(message) => {
+            let error = interpreter.take_error(message);
+            task.borrow_mut().state = TaskState::Failed(error);
+            return;
+        }
+    };
+    match state {
+        TaskState::Running(generator, value) => {
+            task.borrow_mut().state = TaskState::Running(generator, Value::Nil);
+            interpreter.scheduler().block(task, value);
+        }
+        state => task.borrow_mut().state = state,
+    }
+}
+
+// gives back the state the task is in after its turn, a running task holds the value it gave with 'lever'
+fn run_task(interpreter: &mut Interpreter, state: TaskState) -> Result<TaskState, Message> {
+    let (generator, sent) = match state {
+        TaskState::New(callee, args) => match interpreter.call_value(&callee, args)? {
+            Value::Generator(generator) => (generator, Value::Nil),
+            // a proces without 'lever' is done in one go
+            value => return Ok(TaskState::Done(value)),
+        },
+        TaskState::Running(generator, sent) => (generator, sent),
+        TaskState::Done(_) | TaskState::Failed(_) => panic!("Unreachable."),
+    };
+
+    match interpreter.resume_generator(&generator, sent)? {
+        Some(value) => Ok(TaskState::Running(generator, value)),
+        None => {
+            let value = generator.borrow().returned.clone();
+            Ok(TaskState::Done(value))
+        }
+    }
+}
@@ -1,6 +1,9 @@
-use crate::expr::Expr;
+use std::rc::Rc;
+
+use crate::expr::{Expr, Variable};
 use crate::token::Token;
 
+#[derive(Clone)]
 pub struct If {
     pub should_execute: Expr,
     pub statement: Box<Stmt>,
@@ -14,13 +17,170 @@ impl If {
     }
 }
 
+// naam, groet = "Hallo" or ...getallen
+pub struct Param {
+    pub name: Token,
+    pub default: Option<Expr>,
+    pub rest: bool,
+}
+impl Param {
+    pub fn new(name: Token, default: Option<Expr>, rest: bool) -> Self {
+        Param {
+            name,
+            default,
+            rest,
+        }
+    }
+}
+
+pub struct Fun {
+    pub name: Token,
+    pub params: Vec<Param>,
+    pub body: Vec<Stmt>,
+    // a proces with 'lever' in it gives back a generator when it is called
+    pub generator: bool,
+}
+impl Fun {
+    pub fn new(name: Token, params: Vec<Param>, body: Vec<Stmt>) -> Self {
+        let generator = body.iter().any(|stmt| stmt.yields());
+        Fun {
+            name,
+            params,
+            body,
+            generator,
+        }
+    }
+}
+
+// klas Hond implementeert Dier { vast naam; init(naam) { ... } }
+#[derive(Clone)]
+pub struct Class {
+    pub name: Token,
+    pub traits: Vec<Variable>,
+    pub consts: Vec<Token>,
+    pub methods: Vec<Rc<Fun>>,
+}
+impl Class {
+    pub fn new(
+        name: Token,
+        traits: Vec<Variable>,
+        consts: Vec<Token>,
+        methods: Vec<Rc<Fun>>,
+    ) -> Self {
+        Class {
+            name,
+            traits,
+            consts,
+            methods,
+        }
+    }
+}
+
+// eigenschap Dier { geluid(); beschrijf() { ... } }, the methods without a body are required
+#[derive(Clone)]
+pub struct Trait {
+    pub name: Token,
+    pub required: Vec<Token>,
+    pub methods: Vec<Rc<Fun>>,
+}
+impl Trait {
+    pub fn new(name: Token, required: Vec<Token>, methods: Vec<Rc<Fun>>) -> Self {
+        Trait {
+            name,
+            required,
+            methods,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Import {
+    pub keyword: Token,
+    pub path: Token,
+    pub name: Token,
+}
+impl Import {
+    pub fn new(keyword: Token, path: Token, name: Token) -> Self {
+        Import {
+            keyword,
+            path,
+            name,
+        }
+    }
+}
+
+// the left side of a destructuring 'laat', e.g. [a, [b, c]] or {naam, leeftijd}
+#[derive(Clone)]
+pub enum Pattern {
+    Name(Token),
+    List(Token, Vec<Pattern>),
+    Fields(Vec<Token>),
+}
+impl Pattern {
+    pub fn names(&self) -> Vec<Token> {
+        match self {
+            Pattern::Name(name) => vec![name.clone()],
+            Pattern::List(_, patterns) => patterns.iter().flat_map(|p| p.names()).collect(),
+            Pattern::Fields(names) => names.clone(),
+        }
+    }
+}
+
+#[derive(Clone)]
 pub enum Stmt {
     Expr(Expr),
     Print(Expr),
     Println(Expr),
     Var(Token, Expr),
+    Const(Token, Expr),
+    Destructure(Pattern, Expr),
+    MultiAssign(Vec<Variable>, Token, Vec<Expr>),
     Block(Vec<Stmt>),
     If(If, Vec<If>, Option<Box<Stmt>>),
-    While(Expr, Box<Stmt>),
+    While(Token, Expr, Box<Stmt>),
     For(Token, Expr, Expr, Box<Stmt>),
+    ForIn(Pattern, Token, Expr, Box<Stmt>),
+    Fun(Rc<Fun>),
+    Record(Token, Vec<Token>),
+    Class(Class),
+    Trait(Trait),
+    Return(Token, Expr),
+    Import(Import),
+    Export(Vec<Token>, Box<Stmt>),
+}
+impl Stmt {
+    // whether a 'lever' is in this statement, not counting the ones in nested processen
+    pub fn yields(&self) -> bool {
+        match self {
+            Stmt::Expr(Expr::Yield(..)) | Stmt::Var(_, Expr::Yield(..)) => true,
+            Stmt::Expr(Expr::Assign(_, value)) => matches!(**value, Expr::Yield(..)),
+            Stmt::Block(statements) => statements.iter().any(|stmt| stmt.yields()),
+            Stmt::If(first_if, else_ifs, other) => {
+                std::iter::once(first_if)
+                    .chain(else_ifs)
+                    .any(|i| i.statement.yields())
+                    || other.as_ref().is_some_and(|other| other.yields())
+            }
+            Stmt::While(_, _, statement)
+            | Stmt::For(_, _, _, statement)
+            | Stmt::ForIn(_, _, _, statement) => statement.yields(),
+            _ => false,
+        }
+    }
+
+    // whether it puts a name in the scope it is in, a block without those doesn't get a scope
+    pub fn declares(&self) -> bool {
+        matches!(
+            self,
+            Stmt::Var(..)
+                | Stmt::Const(..)
+                | Stmt::Destructure(..)
+                | Stmt::Fun(_)
+                | Stmt::Record(..)
+                | Stmt::Class(_)
+                | Stmt::Trait(_)
+                | Stmt::Import(_)
+                | Stmt::Export(..)
+        )
+    }
 }
@@ -0,0 +1,50 @@
+// finds what someone probably meant when they made a typo
+
+use crate::messages::Text;
+
+// the number of characters that have to be added, removed or changed to get from a to b
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let change = previous[j] + usize::from(ca != *cb);
+            current.push(change.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+// the names that are close enough to be a typo, the closest first, like rustc does it
+pub fn closest<'a>(name: &str, candidates: impl IntoIterator<Item = &'a str>) -> Vec<&'a str> {
+    let max = name.chars().count().max(3) / 3;
+    let mut close: Vec<(usize, &str)> = candidates
+        .into_iter()
+        .filter(|candidate| *candidate != name)
+        .map(|candidate| (edit_distance(name, candidate), candidate))
+        .filter(|(distance, _)| *distance <= max)
+        .collect();
+    close.sort();
+    close.dedup();
+    close
+        .into_iter()
+        .take(3)
+        .map(|(_, candidate)| candidate)
+        .collect()
+}
+
+// "bedoelde je 'a'?" or "bedoelde je 'a' of 'b'?"
+pub fn did_you_mean(options: &[&str]) -> Option<String> {
+    let (last, others) = options.split_last()?;
+    let mut text = others
+        .iter()
+        .map(|option| format!("'{}'", option))
+        .collect::<Vec<_>>()
+        .join(", ");
+    if !text.is_empty() {
+        text += Text::Or.template();
+    }
+    Some(Text::DidYouMean.with(&[&format!("{}'{}'", text, last)]))
+}
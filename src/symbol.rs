@@ -0,0 +1,43 @@
+use std::{cell::RefCell, collections::HashMap, fmt::Display, rc::Rc};
+
+// a name turned into a number when it is scanned, so a scope can find a name without hashing it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Symbol(u32);
+
+#[derive(Default)]
+struct Interner {
+    symbols: HashMap<Rc<str>, Symbol>,
+    names: Vec<Rc<str>>,
+}
+
+thread_local! {
+    // the scanner and the interpreter run on the same thread, so they see the same numbers
+    static INTERNER: RefCell<Interner> = RefCell::new(Interner::default());
+}
+
+impl Symbol {
+    // the same name always gives the same symbol
+    pub fn intern(name: &str) -> Self {
+        INTERNER.with(|interner| {
+            let mut interner = interner.borrow_mut();
+            if let Some(&symbol) = interner.symbols.get(name) {
+                return symbol;
+            }
+            let symbol = Symbol(interner.names.len() as u32);
+            let name: Rc<str> = name.into();
+            interner.names.push(name.clone());
+            interner.symbols.insert(name, symbol);
+            symbol
+        })
+    }
+
+    pub fn name(self) -> Rc<str> {
+        INTERNER.with(|interner| interner.borrow().names[self.0 as usize].clone())
+    }
+}
+
+impl Display for Symbol {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
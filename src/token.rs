@@ -1,6 +1,6 @@
 use std::fmt::Display;
 
-use crate::token_type::TokenType;
+use crate::{error::Span, symbol::Symbol, token_type::TokenType};
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum Literal {
@@ -29,26 +29,34 @@ pub struct Token {
     pub kind: TokenType,
     pub lexeme: String,
     pub literal: Literal,
-    pub line: usize,
+    pub span: Span,
+    // the lexeme as a symbol, that is how the interpreter stores the names it declares
+    pub symbol: Symbol,
 }
 impl Token {
-    pub fn new(kind: TokenType, lexeme: String, literal: Literal, line: usize) -> Self {
+    pub fn new(kind: TokenType, lexeme: String, literal: Literal, span: Span) -> Self {
         Self {
             kind,
+            symbol: Symbol::intern(&lexeme),
             lexeme,
             literal,
-            line,
+            span,
         }
     }
 
-    pub fn to_string(&self) -> String {
-        //format!("{:?}{}{}", self.kind, self.lexeme, self.literal)
+    // the symbol has to change with the lexeme
+    pub fn rename(&mut self, lexeme: String) {
+        self.symbol = Symbol::intern(&lexeme);
+        self.lexeme = lexeme;
+    }
+}
+impl Display for Token {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        //write!(f, "{:?}{}{}", self.kind, self.lexeme, self.literal)
         match &self.literal {
-            Literal::Str(s) => s.clone(),
-            Literal::Num(n) => n.to_string(),
-            _ => self.lexeme.clone(),
-            /* _ => "".to_string(), */
+            Literal::Str(s) => write!(f, "{}", s),
+            Literal::Num(n) => write!(f, "{}", n),
+            _ => write!(f, "{}", self.lexeme),
         }
-        //self.lexeme.clone()
     }
 }
@@ -4,12 +4,17 @@ pub enum TokenType {
     From,
     Until,
     Caret,
+    Question,
     LeftParen,
     RightParen,
     LeftBrace,
     RightBrace,
+    LeftBracket,
+    RightBracket,
     Comma,
+    Colon,
     Dot,
+    Ellipsis,
     Minus,
     Plus,
     Semicolon,
@@ -21,6 +26,8 @@ pub enum TokenType {
     BangEqual,
     Equal,
     EqualEqual,
+    Arrow,
+    Pipe,
     Greater,
     GreaterEqual,
     Less,
@@ -48,9 +55,18 @@ pub enum TokenType {
     This,
     True,
     Var,
+    Const,
     While,
+    Import,
+    Export,
+    In,
+    Record,
+    Yield,
+    Trait,
+    Implements,
+    Is,
     //
-    EOF,
+    Eof,
     //Anders,
     //Nietus,
     //Functie,
@@ -1,4 +1,15 @@
-use crate::token::Literal;
+use std::{cell::RefCell, convert::Infallible, fmt::Display, rc::Rc};
+
+use crate::{
+    class::{ClassType, Instance, TraitType},
+    function::{Function, NativeFn},
+    generator::Generator,
+    messages::Text,
+    module::Module,
+    record::{Record, RecordType},
+    scheduler::{Channel, Task, Wait},
+    token::Literal,
+};
 
 #[derive(Debug, Clone)]
 pub enum Value {
@@ -7,18 +18,60 @@ pub enum Value {
     False,
     Num(f64),
     Str(String),
+    Func(Rc<Function>),
+    Native(NativeFn),
+    Ok(Box<Value>),
+    Fout(Box<Value>),
+    Module(Rc<Module>),
+    List(Rc<RefCell<Vec<Value>>>),
+    Tuple(Rc<Vec<Value>>),
+    Map(Rc<RefCell<Vec<(Value, Value)>>>),
+    RecordType(Rc<RecordType>),
+    Record(Rc<Record>),
+    ClassType(Rc<ClassType>),
+    TraitType(Rc<TraitType>),
+    Instance(Rc<Instance>),
+    Generator(Rc<RefCell<Generator>>),
+    Task(Rc<RefCell<Task>>),
+    Channel(Rc<RefCell<Channel>>),
+    Wait(Rc<Wait>),
 }
-impl Value {
-    pub fn to_string(&self) -> String {
+impl Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Value::Nil => "niks".to_string(),
-            Value::True => "wellus".to_string(),
-            Value::False => "nietus".to_string(),
-            Value::Num(num) => num.to_string(),
-            Value::Str(str) => str.to_string(),
+            Value::Nil => write!(f, "niks"),
+            Value::True => write!(f, "wellus"),
+            Value::False => write!(f, "nietus"),
+            Value::Num(num) => write!(f, "{}", num),
+            Value::Str(str) => write!(f, "{}", str),
+            Value::Func(fun) => write!(f, "<proces {}>", fun.name()),
+            Value::Native(native) => write!(f, "{}", Text::NativeValue.with(&[&native.name])),
+            Value::Module(module) => write!(f, "<module {}>", module.name),
+            Value::RecordType(kind) => write!(f, "<record {}>", kind.name),
+            Value::ClassType(class) => write!(f, "<klas {}>", class.name),
+            Value::TraitType(trait_type) => write!(f, "<eigenschap {}>", trait_type.name),
+            Value::Instance(instance) => write!(f, "<{} object>", instance.class.name),
+            Value::Generator(generator) => write!(f, "<generator {}>", generator.borrow().name),
+            Value::Task(task) => write!(f, "<taak {}>", task.borrow().name),
+            Value::Channel(_) => write!(f, "<kanaal>"),
+            Value::Wait(wait) => match &**wait {
+                Wait::Sleep(ms) => write!(f, "<slaap {}>", ms),
+                Wait::Receive(_) => write!(f, "<ontvang>"),
+            },
+            // values that hold other values are written like to_text_with does, so a list that holds itself ends
+            Value::Ok(_)
+            | Value::Fout(_)
+            | Value::List(_)
+            | Value::Tuple(_)
+            | Value::Map(_)
+            | Value::Record(_) => {
+                let Ok(text) = self.to_text_with(&mut |_| Ok::<_, Infallible>(None));
+                write!(f, "{}", text)
+            }
         }
     }
-
+}
+impl Value {
     pub fn from_lit(lit: &Literal) -> Self {
         match lit {
             Literal::None => panic!("Unreachable."),
@@ -30,6 +83,33 @@ impl Value {
         }
     }
 
+    pub fn from_vec(values: Vec<Value>) -> Value {
+        Value::List(Rc::new(RefCell::new(values)))
+    }
+
+    pub fn from_pairs(pairs: Vec<(Value, Value)>) -> Value {
+        let mut map = Vec::new();
+        for (key, value) in pairs {
+            map_insert(&mut map, key, value);
+        }
+        Value::Map(Rc::new(RefCell::new(map)))
+    }
+
+    // the values that 'voor x in ...' goes through, a map gives (key, value) tuples
+    pub fn iter_values(&self) -> Option<Vec<Value>> {
+        match self {
+            Value::List(list) => Some(list.borrow().clone()),
+            Value::Tuple(tuple) => Some(tuple.to_vec()),
+            Value::Str(str) => Some(str.chars().map(|c| Value::Str(c.to_string())).collect()),
+            Value::Map(map) => Some(
+                (map.borrow().iter())
+                    .map(|(key, value)| Value::Tuple(Rc::new(vec![key.clone(), value.clone()])))
+                    .collect(),
+            ),
+            _ => None,
+        }
+    }
+
     pub fn from_bool(is_true: bool) -> Value {
         if is_true {
             return Value::True;
@@ -46,13 +126,225 @@ impl Value {
     }
 
     pub fn is_equal(value1: &Value, value2: &Value) -> bool {
-        match (value1, value2) {
+        let Ok(equal) = Value::is_equal_with(value1, value2, &mut |_, _| Ok::<_, Infallible>(None));
+        equal
+    }
+
+    // 'gelijk' gives back the answer of the gelijk method of an object, or None when it has none
+    pub fn is_equal_with<E>(
+        value1: &Value,
+        value2: &Value,
+        gelijk: &mut impl FnMut(&Rc<Instance>, &Value) -> Result<Option<bool>, E>,
+    ) -> Result<bool, E> {
+        Value::is_equal_seen(value1, value2, gelijk, &mut Vec::new())
+    }
+
+    // 'seen' holds the pairs of lists and maps that are being compared, meeting a pair again means
+    // a list holds itself, and then that part is equal when the rest is
+    fn is_equal_seen<E>(
+        value1: &Value,
+        value2: &Value,
+        gelijk: &mut impl FnMut(&Rc<Instance>, &Value) -> Result<Option<bool>, E>,
+        seen: &mut Vec<(usize, usize)>,
+    ) -> Result<bool, E> {
+        let pair = match (value1, value2) {
+            (Value::List(list1), Value::List(list2)) => {
+                Some((Rc::as_ptr(list1) as usize, Rc::as_ptr(list2) as usize))
+            }
+            (Value::Map(map1), Value::Map(map2)) => {
+                Some((Rc::as_ptr(map1) as usize, Rc::as_ptr(map2) as usize))
+            }
+            _ => None,
+        };
+        if let Some(pair) = pair {
+            if seen.contains(&pair) {
+                return Ok(true);
+            }
+            seen.push(pair);
+        }
+        let equal = Value::compare(value1, value2, gelijk, seen);
+        if pair.is_some() {
+            seen.pop();
+        }
+        equal
+    }
+
+    fn compare<E>(
+        value1: &Value,
+        value2: &Value,
+        gelijk: &mut impl FnMut(&Rc<Instance>, &Value) -> Result<Option<bool>, E>,
+        seen: &mut Vec<(usize, usize)>,
+    ) -> Result<bool, E> {
+        let equal = match (value1, value2) {
             (Value::Nil, Value::Nil) => true,
             (Value::True, Value::True) => true,
             (Value::False, Value::False) => true,
             (Value::Num(num1), Value::Num(num2)) => num1 == num2,
             (Value::Str(str1), Value::Str(str2)) => str1 == str2,
+            (Value::Func(fun1), Value::Func(fun2)) => Rc::ptr_eq(fun1, fun2),
+            (Value::Native(native1), Value::Native(native2)) => native1.name == native2.name,
+            (Value::Ok(value1), Value::Ok(value2)) => {
+                Value::is_equal_seen(value1, value2, gelijk, seen)?
+            }
+            (Value::Fout(value1), Value::Fout(value2)) => {
+                Value::is_equal_seen(value1, value2, gelijk, seen)?
+            }
+            (Value::Module(module1), Value::Module(module2)) => Rc::ptr_eq(module1, module2),
+            (Value::ClassType(class1), Value::ClassType(class2)) => Rc::ptr_eq(class1, class2),
+            (Value::TraitType(trait1), Value::TraitType(trait2)) => Rc::ptr_eq(trait1, trait2),
+            // the object on the left decides, unless only the one on the right knows how to
+            (Value::Instance(instance), other) | (other, Value::Instance(instance)) => {
+                match gelijk(instance, other)? {
+                    Some(equal) => equal,
+                    None => match (value1, value2) {
+                        (Value::Instance(instance1), Value::Instance(instance2)) => {
+                            Rc::ptr_eq(instance1, instance2)
+                        }
+                        _ => false,
+                    },
+                }
+            }
+            (Value::Generator(gen1), Value::Generator(gen2)) => Rc::ptr_eq(gen1, gen2),
+            (Value::Task(task1), Value::Task(task2)) => Rc::ptr_eq(task1, task2),
+            (Value::Channel(channel1), Value::Channel(channel2)) => Rc::ptr_eq(channel1, channel2),
+            (Value::Wait(wait1), Value::Wait(wait2)) => Rc::ptr_eq(wait1, wait2),
+            (Value::List(list1), Value::List(list2)) => {
+                Value::all_equal(&list1.borrow(), &list2.borrow(), gelijk, seen)?
+            }
+            (Value::Tuple(tuple1), Value::Tuple(tuple2)) => {
+                Value::all_equal(tuple1, tuple2, gelijk, seen)?
+            }
+            (Value::Map(map1), Value::Map(map2)) => {
+                let (map1, map2) = (map1.borrow(), map2.borrow());
+                if map1.len() != map2.len() {
+                    return Ok(false);
+                }
+                for (key, value1) in map1.iter() {
+                    match map_get(&map2, key) {
+                        Some(value2) if Value::is_equal_seen(value1, &value2, gelijk, seen)? => {}
+                        _ => return Ok(false),
+                    }
+                }
+                true
+            }
+            (Value::RecordType(kind1), Value::RecordType(kind2)) => Rc::ptr_eq(kind1, kind2),
+            (Value::Record(record1), Value::Record(record2)) => {
+                Rc::ptr_eq(&record1.kind, &record2.kind)
+                    && Value::all_equal(&record1.values, &record2.values, gelijk, seen)?
+            }
             _ => false,
+        };
+        Ok(equal)
+    }
+
+    fn all_equal<E>(
+        values1: &[Value],
+        values2: &[Value],
+        gelijk: &mut impl FnMut(&Rc<Instance>, &Value) -> Result<Option<bool>, E>,
+        seen: &mut Vec<(usize, usize)>,
+    ) -> Result<bool, E> {
+        if values1.len() != values2.len() {
+            return Ok(false);
+        }
+        for (value1, value2) in values1.iter().zip(values2.iter()) {
+            if !Value::is_equal_seen(value1, value2, gelijk, seen)? {
+                return Ok(false);
+            }
         }
+        Ok(true)
+    }
+
+    // like to_string, but 'als_tekst' gives the text of an object, or None when it has no als_tekst method
+    pub fn to_text_with<E>(
+        &self,
+        als_tekst: &mut impl FnMut(&Rc<Instance>) -> Result<Option<String>, E>,
+    ) -> Result<String, E> {
+        self.to_text_seen(als_tekst, &mut Vec::new())
+    }
+
+    // 'seen' holds the lists and maps that are being written, one that holds itself is written as [...] or {...}
+    fn to_text_seen<E>(
+        &self,
+        als_tekst: &mut impl FnMut(&Rc<Instance>) -> Result<Option<String>, E>,
+        seen: &mut Vec<usize>,
+    ) -> Result<String, E> {
+        let pointer = match self {
+            Value::List(list) => Rc::as_ptr(list) as usize,
+            Value::Map(map) => Rc::as_ptr(map) as usize,
+            _ => return self.to_text_inner(als_tekst, seen),
+        };
+        if seen.contains(&pointer) {
+            let text = if let Value::List(_) = self {
+                "[...]"
+            } else {
+                "{...}"
+            };
+            return Ok(text.to_string());
+        }
+        seen.push(pointer);
+        let text = self.to_text_inner(als_tekst, seen);
+        seen.pop();
+        text
+    }
+
+    fn to_text_inner<E>(
+        &self,
+        als_tekst: &mut impl FnMut(&Rc<Instance>) -> Result<Option<String>, E>,
+        seen: &mut Vec<usize>,
+    ) -> Result<String, E> {
+        let mut texts = |values: &[Value], seen: &mut Vec<usize>| -> Result<String, E> {
+            let texts: Result<Vec<String>, E> = values
+                .iter()
+                .map(|v| v.to_text_seen(als_tekst, seen))
+                .collect();
+            Ok(texts?.join(", "))
+        };
+        let text = match self {
+            Value::Instance(instance) => match als_tekst(instance)? {
+                Some(text) => text,
+                None => self.to_string(),
+            },
+            Value::Ok(value) => format!("Ok({})", value.to_text_seen(als_tekst, seen)?),
+            Value::Fout(value) => format!("Fout({})", value.to_text_seen(als_tekst, seen)?),
+            Value::List(list) => format!("[{}]", texts(&list.borrow(), seen)?),
+            // written like it is made, so (1,) can't be mistaken for the number 1
+            Value::Tuple(tuple) if tuple.len() == 1 => format!("({},)", texts(tuple, seen)?),
+            Value::Tuple(tuple) => format!("({})", texts(tuple, seen)?),
+            Value::Map(map) => {
+                let mut pairs = Vec::new();
+                for (key, value) in map.borrow().iter() {
+                    let key = key.to_text_seen(als_tekst, seen)?;
+                    pairs.push(format!("{}: {}", key, value.to_text_seen(als_tekst, seen)?));
+                }
+                format!("{{{}}}", pairs.join(", "))
+            }
+            Value::Record(record) => {
+                let mut fields = Vec::new();
+                for (field, value) in record.kind.fields.iter().zip(record.values.iter()) {
+                    fields.push(format!(
+                        "{}: {}",
+                        field,
+                        value.to_text_seen(als_tekst, seen)?
+                    ));
+                }
+                format!("{}({})", record.kind.name, fields.join(", "))
+            }
+            _ => self.to_string(),
+        };
+        Ok(text)
+    }
+}
+
+// maps are kept as a list of pairs, as values can't be hashed
+pub fn map_get(map: &[(Value, Value)], key: &Value) -> Option<Value> {
+    (map.iter())
+        .find(|(k, _)| Value::is_equal(k, key))
+        .map(|(_, value)| value.clone())
+}
+
+pub fn map_insert(map: &mut Vec<(Value, Value)>, key: Value, value: Value) {
+    match map.iter_mut().find(|(k, _)| Value::is_equal(k, &key)) {
+        Some((_, old_value)) => *old_value = value,
+        None => map.push((key, value)),
     }
 }
@@ -0,0 +1,91 @@
+mod common;
+
+use common::run;
+
+#[test]
+fn a_klas_has_methods_and_fields() {
+    let run = run("
+        klas Teller {
+            init(start) {
+                dit.stand = start;
+            }
+            verhoog() {
+                dit.stand = dit.stand + 1;
+                retour dit;
+            }
+        }
+        laat t = Teller(5);
+        t.verhoog().verhoog();
+        roep t.stand;
+        roep t is Teller;
+    ");
+    assert_eq!(run.code, 0, "{}", run.stderr);
+    assert_eq!(run.lines(), ["7", "wellus"]);
+}
+
+#[test]
+fn an_eigenschap_gives_its_default_methods() {
+    let run = run("
+        eigenschap Dier {
+            geluid();
+            beschrijf() {
+                retour dit.naam + \" zegt \" + dit.geluid();
+            }
+        }
+        klas Hond implementeert Dier {
+            vast naam;
+            init(naam) {
+                dit.naam = naam;
+            }
+            geluid() {
+                retour \"woef\";
+            }
+        }
+        laat rex = Hond(\"Rex\");
+        roep rex.beschrijf();
+        roep rex is Dier;
+        roep 3 is Dier;
+    ");
+    assert_eq!(run.code, 0, "{}", run.stderr);
+    assert_eq!(run.lines(), ["Rex zegt woef", "wellus", "nietus"]);
+}
+
+#[test]
+fn a_vast_field_can_only_be_set_in_init() {
+    let run = run("
+        klas Hond {
+            vast naam;
+            init(naam) {
+                dit.naam = naam;
+            }
+        }
+        laat rex = Hond(\"Rex\");
+        roep rex.naam;
+        rex.naam = \"Max\";
+        roep rex.naam;
+    ");
+    assert_ne!(run.code, 0);
+    assert_eq!(run.lines(), ["Rex"]);
+    assert!(run.stderr.contains("naam"), "{}", run.stderr);
+}
+
+#[test]
+fn a_klas_without_a_method_of_its_eigenschap_is_found_before_running() {
+    let run = run("
+        roep 1;
+        eigenschap Dier {
+            geluid();
+        }
+        proces maak() {
+            klas Steen implementeert Dier {
+                gewicht() {
+                    retour 3;
+                }
+            }
+        }
+    ");
+    assert_eq!(run.code, 65);
+    assert_eq!(run.codes(), ["R0206"]);
+    assert!(run.stderr.contains("geluid"), "{}", run.stderr);
+    assert!(run.stdout.is_empty());
+}
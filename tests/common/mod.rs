@@ -0,0 +1,85 @@
+// every test file has its own copy of this module, and not every file uses all of it
+#![allow(dead_code)]
+
+use std::{
+    env, fs,
+    path::PathBuf,
+    process::{self, Command},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+// what a run of the lox binary printed, and how it ended
+pub struct Run {
+    pub code: i32,
+    pub stdout: String,
+    pub stderr: String,
+}
+impl Run {
+    // the error and warning codes in the order they were printed, like R0102
+    pub fn codes(&self) -> Vec<&str> {
+        (self.stderr.match_indices("[R"))
+            .map(|(i, _)| &self.stderr[i + 1..i + 6])
+            .collect()
+    }
+
+    pub fn lines(&self) -> Vec<&str> {
+        self.stdout.lines().collect()
+    }
+}
+
+static DIRS: AtomicUsize = AtomicUsize::new(0);
+
+// every test gets its own directory, the tests run at the same time
+fn test_dir() -> PathBuf {
+    let number = DIRS.fetch_add(1, Ordering::Relaxed);
+    let dir = env::temp_dir()
+        .join(format!("rox-tests-{}", process::id()))
+        .join(format!("test{}", number));
+    fs::create_dir_all(&dir).expect("could not make the test directory");
+    dir
+}
+
+// lox runs file.lox when it isn't given a file, so the source is put there in a directory of
+// its own, and it runs in English and without colours so the output is easy to compare
+pub fn run(source: &str) -> Run {
+    run_with(source, &[])
+}
+
+// the arguments come after 'lox' on the command line
+pub fn run_with(source: &str, args: &[&str]) -> Run {
+    lox(source, &[], &[&["--taal=engels"], args].concat(), &[])
+}
+
+// the other files are put next to file.lox, for the modules it imports
+pub fn run_with_files(source: &str, files: &[(&str, &str)]) -> Run {
+    lox(source, files, &["--taal=engels"], &[])
+}
+
+// without picking a language, for the tests of the languages themselves
+pub fn run_with_env(source: &str, args: &[&str], vars: &[(&str, &str)]) -> Run {
+    lox(source, &[], args, vars)
+}
+
+fn lox(source: &str, files: &[(&str, &str)], args: &[&str], vars: &[(&str, &str)]) -> Run {
+    let dir = test_dir();
+    fs::write(dir.join("file.lox"), source).expect("could not write the test file");
+    for (name, source) in files {
+        let path = dir.join(name);
+        fs::create_dir_all(path.parent().unwrap()).expect("could not make the module directory");
+        fs::write(path, source).expect("could not write the module");
+    }
+    let output = Command::new(env!("CARGO_BIN_EXE_lox"))
+        .args(args)
+        .current_dir(&dir)
+        .env("NO_COLOR", "1")
+        .env_remove("ROX_TAAL")
+        .envs(vars.iter().copied())
+        .output()
+        .expect("could not run lox");
+    let _ = fs::remove_dir_all(&dir);
+    Run {
+        code: output.status.code().unwrap_or(-1),
+        stdout: String::from_utf8_lossy(&output.stdout).to_string(),
+        stderr: String::from_utf8_lossy(&output.stderr).to_string(),
+    }
+}
@@ -0,0 +1,43 @@
+mod common;
+
+use common::run;
+
+#[test]
+fn a_list_comprehension_can_loop_and_filter() {
+    let run = run("
+        roep [x * x voor x in 0 tot 10 als x > 6];
+        roep [x + y voor x in [1, 2] voor y in [10, 20]];
+        roep [letter voor letter in \"rox\"];
+    ");
+    assert_eq!(run.code, 0, "{}", run.stderr);
+    assert_eq!(
+        run.lines(),
+        ["[49, 64, 81]", "[11, 21, 12, 22]", "[r, o, x]"]
+    );
+}
+
+#[test]
+fn a_map_comprehension_builds_a_map() {
+    let run = run("
+        laat lengtes = {woord: lengte(woord) voor woord in [\"aap\", \"noot\"]};
+        roep lengtes[\"noot\"];
+        lengtes[\"mies\"] = 4;
+        roep lengtes.mies + lengtes.aap;
+        laat {aap} = lengtes;
+        roep aap;
+    ");
+    assert_eq!(run.code, 0, "{}", run.stderr);
+    assert_eq!(run.lines(), ["4", "7", "3"]);
+}
+
+#[test]
+fn the_variables_of_a_comprehension_stay_inside_it() {
+    let run = run("
+        laat x = \"buiten\";
+        laat kwadraten = [x * x voor x in [1, 2, 3]];
+        roep x;
+        roep kwadraten;
+    ");
+    assert_eq!(run.code, 0, "{}", run.stderr);
+    assert_eq!(run.lines(), ["buiten", "[1, 4, 9]"]);
+}
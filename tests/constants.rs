@@ -0,0 +1,82 @@
+mod common;
+
+use common::run;
+
+#[test]
+fn a_vast_can_be_read() {
+    let run = run("
+        vast PI = 3;
+        proces omtrek(r) {
+            retour 2 * PI * r;
+        }
+        roep omtrek(2);
+    ");
+    assert_eq!(run.code, 0, "{}", run.stderr);
+    assert_eq!(run.lines(), ["12"]);
+}
+
+#[test]
+fn changing_a_vast_is_found_before_running() {
+    let run = run("
+        roep \"begin\";
+        vast PI = 3.14;
+        PI = 3;
+    ");
+    assert_ne!(run.code, 0);
+    assert!(run.stdout.is_empty(), "{}", run.stdout);
+    assert!(run.stderr.contains("PI"), "{}", run.stderr);
+}
+
+#[test]
+fn a_proces_can_not_change_a_vast_declared_after_it_either() {
+    let run = run("
+        proces verander() {
+            LATER = 2;
+        }
+        vast LATER = 1;
+        roep LATER;
+        verander();
+        roep LATER;
+    ");
+    assert_eq!(run.code, 65);
+    assert_eq!(run.codes(), ["R0101"]);
+    assert!(run.stdout.is_empty(), "{}", run.stdout);
+}
+
+#[test]
+fn a_changed_vast_points_at_its_declaration() {
+    let run = run("vast PI = 3;\nroep \"a\";\nPI = 4;\n");
+    assert!(run.stderr.contains(" --> file.lox:3:1\n"), "{}", run.stderr);
+    assert!(
+        run.stderr.contains("1 | vast PI = 3;\n  |      --"),
+        "{}",
+        run.stderr
+    );
+}
+
+#[test]
+fn a_vast_can_not_be_declared_again() {
+    let run = run("
+        vast a = 1;
+        laat a = 2;
+        a = 3;
+        roep a;
+    ");
+    assert_eq!(run.code, 65);
+    assert_eq!(run.codes(), ["R0101"]);
+    assert!(run.stdout.is_empty());
+}
+
+#[test]
+fn a_vast_can_be_shadowed_in_a_block() {
+    let run = run("
+        vast a = 1;
+        {
+            laat a = 2;
+            a = 3;
+            roep a;
+        }
+    ");
+    assert_eq!(run.code, 0, "{}", run.stderr);
+    assert_eq!(run.lines(), ["3"]);
+}
@@ -0,0 +1,81 @@
+mod common;
+
+use common::run;
+
+#[test]
+fn nested_lists_can_be_destructured() {
+    let run = run("
+        laat lijst = [1, [2, 3]];
+        laat [a, [b, c]] = lijst;
+        roep a + b + c;
+        lijst[1][0] = 20;
+        roep lijst[1][0] + lengte(lijst);
+    ");
+    assert_eq!(run.code, 0, "{}", run.stderr);
+    assert_eq!(run.lines(), ["6", "22"]);
+}
+
+#[test]
+fn every_value_is_worked_out_before_assigning() {
+    let run = run("
+        laat a = 1;
+        laat b = 2;
+        a, b = b, a;
+        roep a + \" \" + b;
+        a, b = [5, 6];
+        roep a + \" \" + b;
+    ");
+    assert_eq!(run.code, 0, "{}", run.stderr);
+    assert_eq!(run.lines(), ["2 1", "5 6"]);
+}
+
+#[test]
+fn the_exports_of_a_module_can_be_destructured() {
+    let run = run("
+        importeer wiskunde;
+        laat {wortel, abs} = wiskunde;
+        roep wortel(9) + abs(-1);
+    ");
+    assert_eq!(run.code, 0, "{}", run.stderr);
+    assert_eq!(run.lines(), ["4"]);
+}
+
+#[test]
+fn a_pattern_that_does_not_fit_is_an_error() {
+    let run = run("
+        roep \"begin\";
+        laat [a, b] = [1, 2, 3];
+        roep a;
+    ");
+    assert_ne!(run.code, 0);
+    assert_eq!(run.lines(), ["begin"]);
+}
+
+#[test]
+fn a_list_that_holds_itself_can_be_printed_and_compared() {
+    let run = run("
+        laat l = [1];
+        voeg_toe(l, l);
+        roep l;
+        l[0] = l;
+        roep l;
+        laat a = [1];
+        voeg_toe(a, a);
+        laat b = [1];
+        voeg_toe(b, b);
+        roep a == b;
+        laat d = {\"x\": 1};
+        d[\"zelf\"] = d;
+        roep d;
+    ");
+    assert_eq!(run.code, 0, "{}", run.stderr);
+    assert_eq!(
+        run.lines(),
+        [
+            "[1, [...]]",
+            "[[...], [...]]",
+            "wellus",
+            "{x: 1, zelf: {...}}"
+        ]
+    );
+}
@@ -0,0 +1,120 @@
+mod common;
+
+use common::{run, run_with};
+
+#[test]
+fn a_syntax_error_stops_before_running() {
+    let run = run("
+        roep \"begin\";
+        laat = 3;
+    ");
+    assert_eq!(run.code, 65);
+    assert!(run.stdout.is_empty(), "{}", run.stdout);
+}
+
+#[test]
+fn an_error_while_running_stops_the_program() {
+    let run = run("
+        roep \"begin\";
+        roep 1 / niks;
+        roep \"einde\";
+    ");
+    assert_eq!(run.code, 70);
+    assert_eq!(run.lines(), ["begin"]);
+}
+
+#[test]
+fn a_file_that_can_not_be_read_is_reported() {
+    let run = run_with("", &["bestaat_niet.lox"]);
+    assert_eq!(run.code, 66);
+}
+
+#[test]
+fn probeer_catches_every_error_while_running() {
+    let run = run("
+        laat l = [1, 2];
+        roep is_fout(probeer(() => l[5]));
+        roep is_fout(probeer(() => 1 / niks));
+        roep is_fout(probeer(() => niks()));
+        roep probeer(() => l[1]);
+    ");
+    assert_eq!(run.code, 0, "{}", run.stderr);
+    assert_eq!(run.lines(), ["wellus", "wellus", "wellus", "Ok(2)"]);
+}
+
+#[test]
+fn every_syntax_error_is_reported_at_once() {
+    let source = "
+        laat = 1;
+        laat = 2;
+        roep (3;
+        roep \"niet gedraaid\";
+    ";
+    let all = run(source);
+    assert_eq!(all.code, 65);
+    assert_eq!(all.stderr.matches("Error").count(), 3, "{}", all.stderr);
+    assert!(all.stdout.is_empty(), "{}", all.stdout);
+
+    let capped = run_with(source, &["--max-fouten=2"]);
+    assert_eq!(capped.code, 65);
+    assert_eq!(
+        capped.stderr.matches("Error").count(),
+        2,
+        "{}",
+        capped.stderr
+    );
+}
+
+#[test]
+fn an_error_underlines_where_it_is_in_the_line() {
+    let run = run("roep \"a\";\nroep 1 + niks;\n");
+    assert_eq!(run.code, 70);
+    assert!(run.stderr.contains(" --> file.lox:2:8\n"), "{}", run.stderr);
+    assert!(
+        run.stderr.contains("2 | roep 1 + niks;\n  |        ^\n"),
+        "{}",
+        run.stderr
+    );
+}
+
+#[test]
+fn a_missing_semicolon_is_shown_after_the_previous_token() {
+    let run = run("roep 1\nroep 2;\n");
+    assert_eq!(run.code, 65);
+    assert!(run.stderr.contains(" --> file.lox:1:7\n"), "{}", run.stderr);
+}
+
+#[test]
+fn scan_and_parse_errors_are_shown_together() {
+    let run = run("
+        laat a = 1 # 2;
+        laat b = ;
+        roep \"x\"
+        laat c = 3;
+    ");
+    assert_eq!(run.code, 65);
+    assert_eq!(run.codes(), ["R0001", "R0008", "R0003"]);
+    assert!(run.stderr.contains("3 errors found."), "{}", run.stderr);
+}
+
+#[test]
+fn scan_errors_count_towards_the_cap() {
+    let run = run_with(
+        "laat a = 1 # 2;\nlaat b = ;\nlaat c = ;\n",
+        &["--max-fouten=2"],
+    );
+    assert_eq!(run.code, 65);
+    assert_eq!(run.codes(), ["R0001", "R0008"]);
+}
+
+#[test]
+fn columns_count_characters_not_bytes() {
+    let run = run("roep \"€€€\" + 1 + niks;\n");
+    assert_eq!(run.code, 70);
+    assert!(run.stderr.contains("file.lox:1:16\n"), "{}", run.stderr);
+    assert!(
+        run.stderr.contains("\n  |                ^\n"),
+        "{}",
+        run.stderr
+    );
+}
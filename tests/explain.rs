@@ -0,0 +1,74 @@
+mod common;
+
+use common::{run, run_with};
+
+#[test]
+fn an_error_shows_its_code_and_how_to_explain_it() {
+    let run = run("roep 1 / niks;");
+    assert_eq!(run.code, 70);
+    assert_eq!(run.codes(), ["R0209"]);
+    assert!(run.stderr.contains("lox explain R0209"), "{}", run.stderr);
+}
+
+#[test]
+fn explain_shows_a_wrong_and_a_correct_example() {
+    let run = run_with("", &["explain", "R0003"]);
+    assert_eq!(run.code, 0, "{}", run.stderr);
+    assert!(
+        run.stdout.starts_with("R0003 Expected ';'."),
+        "{}",
+        run.stdout
+    );
+    assert!(
+        run.stdout.contains("Wrong:\n    laat breedte = 3\n"),
+        "{}",
+        run.stdout
+    );
+    assert!(run.stdout.contains("Correct:\n"), "{}", run.stdout);
+}
+
+#[test]
+fn explain_without_a_code_lists_every_code() {
+    let run = run_with("", &["explain"]);
+    assert_eq!(run.code, 0, "{}", run.stderr);
+    assert!(
+        run.lines().contains(&"R0003  Expected ';'."),
+        "{}",
+        run.stdout
+    );
+    assert!(run.lines().len() > 100);
+}
+
+#[test]
+fn explain_refuses_a_code_that_does_not_exist() {
+    let run = run_with("", &["explain", "R9999"]);
+    assert_eq!(run.code, 64);
+    assert!(run.stdout.is_empty());
+}
+
+#[test]
+fn a_range_of_non_numbers_has_its_own_code() {
+    let run = run("voor i van \"a\" tot 3 { roep i; }");
+    assert_eq!(run.code, 70);
+    assert_eq!(run.codes(), ["R0223"]);
+}
+
+// the examples for a missing file and an import cycle need more than one file
+#[test]
+fn every_wrong_example_gives_the_code_it_explains() {
+    let list = run_with("", &["explain"]);
+    for line in list.lines() {
+        let code = &line[..5];
+        if ["R0401", "R0403"].contains(&code) {
+            continue;
+        }
+        let explanation = run_with("", &["explain", code]);
+        let (_, wrong) = explanation.stdout.split_once("Wrong:\n").unwrap();
+        let source: String = (wrong.lines())
+            .take_while(|line| !line.is_empty())
+            .map(|line| format!("{}\n", &line[4..]))
+            .collect();
+        let run = run(&source);
+        assert!(run.codes().contains(&code), "{}\n{}", code, run.stderr);
+    }
+}
@@ -0,0 +1,63 @@
+mod common;
+
+use common::run;
+
+#[test]
+fn a_proces_returns_its_value() {
+    let run = run("
+        proces oppervlakte(breedte, lengte) {
+            retour breedte * lengte;
+        }
+        proces niets() {
+            laat x = 1;
+        }
+        roep oppervlakte(3, 5);
+        roep niets();
+    ");
+    assert_eq!(run.code, 0, "{}", run.stderr);
+    assert_eq!(run.lines(), ["15", "niks"]);
+}
+
+#[test]
+fn a_proces_can_call_itself() {
+    let run = run("
+        proces fib(n) {
+            als n < 2 retour n;
+            retour fib(n - 1) + fib(n - 2);
+        }
+        roep fib(15);
+    ");
+    assert_eq!(run.code, 0, "{}", run.stderr);
+    assert_eq!(run.lines(), ["610"]);
+}
+
+#[test]
+fn a_proces_keeps_the_scope_it_was_declared_in() {
+    let run = run("
+        laat x = \"globaal\";
+        {
+            laat x = \"blok\";
+            proces toon_x() {
+                retour x;
+            }
+            roep toon_x();
+        }
+    ");
+    assert_eq!(run.code, 0, "{}", run.stderr);
+    assert_eq!(run.lines(), ["blok"]);
+}
+
+#[test]
+fn a_question_mark_returns_a_fout_early() {
+    let run = run("
+        proces verdubbel(tekst) {
+            laat getal = probeer_getal(tekst)?;
+            retour Ok(getal * 2);
+        }
+        roep verdubbel(\"21\");
+        roep is_fout(verdubbel(\"banaan\"));
+        roep uitpakken(verdubbel(\"4\"));
+    ");
+    assert_eq!(run.code, 0, "{}", run.stderr);
+    assert_eq!(run.lines(), ["Ok(42)", "wellus", "8"]);
+}
@@ -0,0 +1,92 @@
+mod common;
+
+use common::run;
+
+#[test]
+fn generators_give_their_values_one_by_one() {
+    let run = run("
+        proces tot_drie() {
+            voor i van 0 tot 3 { lever i; }
+        }
+        voor x in tot_drie() { roep x; }
+        proces echo() {
+            laat totaal = 0;
+            terwijl wellus { laat x = lever totaal; totaal = totaal + x; }
+        }
+        laat e = echo();
+        volgende(e);
+        stuur(e, 5);
+        roep stuur(e, 7);
+    ");
+    assert_eq!(run.code, 0, "{}", run.stderr);
+    assert_eq!(run.lines(), ["0", "1", "2", "12"]);
+}
+
+#[test]
+fn a_generator_is_done_after_its_last_value() {
+    let run = run("
+        proces een() {
+            lever 1;
+            retour 2;
+        }
+        laat g = een();
+        roep volgende(g);
+        roep klaar(g);
+        roep volgende(g);
+        roep klaar(g);
+    ");
+    assert_eq!(run.code, 0, "{}", run.stderr);
+    assert_eq!(run.lines(), ["1", "nietus", "niks", "wellus"]);
+}
+
+#[test]
+fn a_range_of_non_numbers_is_an_error() {
+    let run = run("
+        proces g() { voor i van \"a\" tot 3 { lever i; } }
+        voor x in g() { roep x; }
+    ");
+    assert_eq!(run.code, 70);
+    assert_eq!(run.codes(), ["R0223"]);
+}
+
+#[test]
+fn a_question_mark_in_lever_ends_the_generator() {
+    let run = run("
+        proces gen() {
+            lever 1;
+            lever probeer_getal(\"x\")?;
+            lever 3;
+        }
+        voor x in gen() { roep x; }
+    ");
+    assert_eq!(run.code, 0, "{}", run.stderr);
+    assert_eq!(run.lines(), ["1"]);
+}
+
+#[test]
+fn a_question_mark_in_a_loop_condition_ends_the_generator() {
+    let run = run("
+        proces gen(n) {
+            terwijl probeer_getal(n)? < 3 { lever 1; }
+        }
+        laat g = gen(\"x\");
+        roep volgende(g);
+        roep klaar(g);
+    ");
+    assert_eq!(run.code, 0, "{}", run.stderr);
+    assert_eq!(run.lines(), ["niks", "wellus"]);
+}
+
+#[test]
+fn a_question_mark_in_a_generator_does_not_return_from_its_consumer() {
+    let run = run("
+        proces gen() { lever 1; lever probeer_getal(\"x\")?; }
+        proces gebruik() {
+            voor x in gen() { roep x; }
+            retour \"klaar\";
+        }
+        roep gebruik();
+    ");
+    assert_eq!(run.code, 0, "{}", run.stderr);
+    assert_eq!(run.lines(), ["1", "klaar"]);
+}
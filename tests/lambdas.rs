@@ -0,0 +1,65 @@
+mod common;
+
+use common::run;
+
+#[test]
+fn anonymous_functions_are_values() {
+    let run = run("
+        laat dubbel = proces (x) { retour x * 2; };
+        laat drievoud = (x) => x * 3;
+        proces pas_toe(f, x) {
+            retour f(x);
+        }
+        roep dubbel(2) + drievoud(2);
+        roep pas_toe((x) => x + 1, 41);
+    ");
+    assert_eq!(run.code, 0, "{}", run.stderr);
+    assert_eq!(run.lines(), ["10", "42"]);
+}
+
+#[test]
+fn an_anonymous_function_closes_over_its_scope() {
+    let run = run("
+        proces teller() {
+            laat n = 0;
+            retour () => n = n + 1;
+        }
+        laat a = teller();
+        laat b = teller();
+        a();
+        a();
+        roep a();
+        roep b();
+    ");
+    assert_eq!(run.code, 0, "{}", run.stderr);
+    assert_eq!(run.lines(), ["3", "1"]);
+}
+
+#[test]
+fn an_anonymous_proces_can_start_a_statement() {
+    let run = run("
+        proces (x) { roep x; }(7);
+    ");
+    assert_eq!(run.code, 0, "{}", run.stderr);
+    assert_eq!(run.lines(), ["7"]);
+}
+
+#[test]
+fn every_round_of_a_counting_loop_has_its_own_variable() {
+    let run = run("
+        laat fs = [];
+        voor i van 0 tot 3 { voeg_toe(fs, () => i); }
+        roep [f() voor f in fs];
+        laat gs = [];
+        voor i van 3 tot 0 voeg_toe(gs, () => i);
+        roep [g() voor g in gs];
+        proces gen() {
+            voor i van 0 tot 3 {
+                lever () => i;
+            }
+        }
+        roep [f() voor f in gen()];
+    ");
+    assert_eq!(run.code, 0, "{}", run.stderr);
+    assert_eq!(run.lines(), ["[0, 1, 2]", "[3, 2, 1]", "[0, 1, 2]"]);
+}
@@ -0,0 +1,59 @@
+mod common;
+
+use common::run;
+
+#[test]
+fn lists_go_through_processen() {
+    let run = run("
+        laat cijfers = [7, 4, 9, 5];
+        roep kaart_toe(filter(cijfers, (c) => c >= 5), (c) => c * 10);
+        roep vouw(cijfers, (som, c) => som + c, 0);
+        roep som(cijfers) + \" \" + min(cijfers) + \" \" + max(cijfers);
+        roep omgekeerd(cijfers);
+        roep zip([1, 2], [\"a\", \"b\"]);
+        roep enumereer([\"a\"]);
+        voeg_toe(cijfers, 1);
+        roep lengte(cijfers);
+    ");
+    assert_eq!(run.code, 0, "{}", run.stderr);
+    assert_eq!(
+        run.lines(),
+        [
+            "[70, 90, 50]",
+            "25",
+            "25 4 9",
+            "[5, 9, 4, 7]",
+            "[(1, a), (2, b)]",
+            "[(0, a)]",
+            "5"
+        ]
+    );
+}
+
+#[test]
+fn sorteer_keeps_equal_keys_in_their_order() {
+    let run = run("
+        laat mensen = [(\"an\", 30), (\"bo\", 20), (\"cas\", 30), (\"dirk\", 20), (\"eva\", 30)];
+        laat op_leeftijd = sorteer(mensen, (a, b) => a[1] < b[1]);
+        roep kaart_toe(op_leeftijd, (m) => m[0]);
+        roep sorteer([3, 1, 2]);
+        roep sorteer([1, 3, 2], (a, b) => b - a);
+    ");
+    assert_eq!(run.code, 0, "{}", run.stderr);
+    assert_eq!(
+        run.lines(),
+        ["[bo, dirk, an, cas, eva]", "[1, 2, 3]", "[3, 2, 1]"]
+    );
+}
+
+#[test]
+fn an_error_in_a_native_is_shown_at_the_line_of_the_call() {
+    let run = run("
+        laat vergelijk = (a, b) => \"ja\";
+        roep \"begin\";
+        roep sorteer([3, 1, 2], vergelijk);
+    ");
+    assert_ne!(run.code, 0);
+    assert_eq!(run.lines(), ["begin"]);
+    assert!(run.stderr.contains("file.lox:4:"), "{}", run.stderr);
+}
@@ -0,0 +1,55 @@
+mod common;
+
+use common::run_with_env;
+
+const DEEL: &str = "roep 1 / niks;";
+
+#[test]
+fn the_messages_are_playful_by_default() {
+    let run = run_with_env(DEEL, &[], &[]);
+    assert_eq!(run.code, 70);
+    assert!(run.stderr.contains("kaaskop"), "{}", run.stderr);
+}
+
+#[test]
+fn taal_picks_the_language() {
+    let neutraal = run_with_env(DEEL, &["--taal=neutraal"], &[]);
+    assert!(neutraal.stderr.contains("nummers."), "{}", neutraal.stderr);
+    assert!(!neutraal.stderr.contains("kaaskop"), "{}", neutraal.stderr);
+
+    let engels = run_with_env(DEEL, &["--taal=engels"], &[]);
+    assert!(
+        engels.stderr.contains("can only be used on numbers"),
+        "{}",
+        engels.stderr
+    );
+}
+
+#[test]
+fn the_flag_wins_over_rox_taal() {
+    let env = run_with_env(DEEL, &[], &[("ROX_TAAL", "engels")]);
+    assert!(
+        env.stderr.contains("can only be used on numbers"),
+        "{}",
+        env.stderr
+    );
+
+    let flag = run_with_env(DEEL, &["--taal=neutraal"], &[("ROX_TAAL", "engels")]);
+    assert!(flag.stderr.contains("kan alleen"), "{}", flag.stderr);
+}
+
+#[test]
+fn an_unknown_language_is_refused() {
+    let run = run_with_env(DEEL, &["--taal=fries"], &[]);
+    assert_eq!(run.code, 64);
+    assert!(run.stderr.contains("'fries'"), "{}", run.stderr);
+    assert!(run.stdout.is_empty());
+}
+
+#[test]
+fn a_built_in_proces_is_printed_in_the_language() {
+    let nl = run_with_env("roep lengte;", &[], &[]);
+    assert_eq!(nl.lines(), ["<ingebouwd proces lengte>"]);
+    let en = run_with_env("roep lengte;", &["--taal=engels"], &[]);
+    assert_eq!(en.lines(), ["<built-in function lengte>"]);
+}
@@ -0,0 +1,30 @@
+mod common;
+
+use common::run;
+
+#[test]
+fn en_and_of_can_be_chained() {
+    let run = run("
+        roep wellus en wellus en nietus;
+        roep wellus en wellus en wellus;
+        roep nietus of nietus of wellus;
+        roep nietus of nietus of nietus;
+        roep 1 < 2 en 2 < 3 of nietus;
+    ");
+    assert_eq!(run.code, 0, "{}", run.stderr);
+    assert_eq!(
+        run.lines(),
+        ["nietus", "wellus", "wellus", "nietus", "wellus"]
+    );
+}
+
+#[test]
+fn names_can_contain_underscores() {
+    let run = run("
+        laat mijn_getal = 2;
+        laat _verborgen = 3;
+        roep mijn_getal * _verborgen;
+    ");
+    assert_eq!(run.code, 0, "{}", run.stderr);
+    assert_eq!(run.lines(), ["6"]);
+}
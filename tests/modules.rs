@@ -0,0 +1,74 @@
+mod common;
+
+use common::{run, run_with_files};
+
+#[test]
+fn a_module_gives_its_exports() {
+    let vormen = "
+        exporteer proces vierkant(x) {
+            retour x * x;
+        }
+        exporteer laat naam = \"vormen\";
+        laat geheim = 1;
+    ";
+    let run = run_with_files(
+        "
+        importeer \"vormen.lox\";
+        importeer \"vormen.lox\" als v;
+        roep vormen.vierkant(3) + v.vierkant(2);
+        roep v.naam;
+    ",
+        &[("vormen.lox", vormen)],
+    );
+    assert_eq!(run.code, 0, "{}", run.stderr);
+    assert_eq!(run.lines(), ["13", "vormen"]);
+}
+
+#[test]
+fn a_module_is_run_only_once() {
+    let teller = "
+        roep \"teller geladen\";
+        exporteer laat stand = 1;
+    ";
+    let tussen = "
+        importeer \"teller.lox\";
+        exporteer laat dubbel = teller.stand * 2;
+    ";
+    let run = run_with_files(
+        "
+        importeer \"lib/teller.lox\";
+        importeer \"lib/tussen.lox\";
+        roep teller.stand + tussen.dubbel;
+    ",
+        &[("lib/teller.lox", teller), ("lib/tussen.lox", tussen)],
+    );
+    assert_eq!(run.code, 0, "{}", run.stderr);
+    assert_eq!(run.lines(), ["teller geladen", "3"]);
+}
+
+#[test]
+fn wiskunde_is_built_in() {
+    let run = run("
+        importeer wiskunde;
+        roep wiskunde.wortel(16) + wiskunde.abs(-1);
+    ");
+    assert_eq!(run.code, 0, "{}", run.stderr);
+    assert_eq!(run.lines(), ["5"]);
+}
+
+#[test]
+fn an_import_cycle_is_an_error() {
+    let run = run_with_files(
+        "importeer \"a.lox\";",
+        &[
+            ("a.lox", "importeer \"b.lox\";"),
+            ("b.lox", "importeer \"a.lox\";"),
+        ],
+    );
+    assert_ne!(run.code, 0);
+    assert!(
+        run.stderr.contains("a.lox -> b.lox -> a.lox"),
+        "{}",
+        run.stderr
+    );
+}
@@ -0,0 +1,73 @@
+mod common;
+
+use common::run;
+
+const GELD: &str = "
+    klas Geld {
+        vast centen;
+        init(centen) {
+            dit.centen = centen;
+        }
+        plus(ander) {
+            retour Geld(dit.centen + ander.centen);
+        }
+        keer(factor) {
+            retour Geld(dit.centen * factor);
+        }
+        kleiner_dan(ander) {
+            retour dit.centen < ander.centen;
+        }
+        gelijk(ander) {
+            retour dit.centen == ander.centen;
+        }
+        als_tekst() {
+            retour \"€\" + dit.centen / 100;
+        }
+    }
+";
+
+#[test]
+fn operators_call_the_methods_of_an_object() {
+    let run = run(&format!(
+        "{}
+        roep Geld(250) + Geld(125);
+        roep Geld(100) * 3;
+        roep Geld(1) < Geld(2);
+        roep Geld(3) >= Geld(2);
+        roep \"prijs: \" + Geld(50);
+    ",
+        GELD
+    ));
+    assert_eq!(run.code, 0, "{}", run.stderr);
+    assert_eq!(
+        run.lines(),
+        ["€3.75", "€3", "wellus", "wellus", "prijs: €0.5"]
+    );
+}
+
+#[test]
+fn lists_of_objects_use_gelijk_and_als_tekst() {
+    let run = run(&format!(
+        "{}
+        roep [Geld(1), Geld(2)] == [Geld(1), Geld(2)];
+        roep Geld(1) != Geld(2);
+        roep [Geld(5)];
+    ",
+        GELD
+    ));
+    assert_eq!(run.code, 0, "{}", run.stderr);
+    assert_eq!(run.lines(), ["wellus", "wellus", "[€0.05]"]);
+}
+
+#[test]
+fn the_object_has_to_be_on_the_left() {
+    let run = run(&format!(
+        "{}
+        roep \"begin\";
+        roep 2 * Geld(5);
+    ",
+        GELD
+    ));
+    assert_ne!(run.code, 0);
+    assert_eq!(run.lines(), ["begin"]);
+}
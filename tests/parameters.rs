@@ -0,0 +1,59 @@
+mod common;
+
+use common::run;
+
+#[test]
+fn parameters_can_have_a_default_and_be_named() {
+    let run = run("
+        proces groet(naam, groet = \"Hallo\", teken = \"!\") {
+            retour groet + \", \" + naam + teken;
+        }
+        roep groet(\"Jan\");
+        roep groet(naam: \"Jan\", teken: \"?\");
+        roep groet(\"Jan\", \"Hoi\");
+    ");
+    assert_eq!(run.code, 0, "{}", run.stderr);
+    assert_eq!(run.lines(), ["Hallo, Jan!", "Hallo, Jan?", "Hoi, Jan!"]);
+}
+
+#[test]
+fn a_default_can_use_the_parameters_before_it() {
+    let run = run("
+        proces rechthoek(breedte, lengte = breedte) {
+            retour breedte * lengte;
+        }
+        roep rechthoek(3);
+        roep rechthoek(3, 4);
+    ");
+    assert_eq!(run.code, 0, "{}", run.stderr);
+    assert_eq!(run.lines(), ["9", "12"]);
+}
+
+#[test]
+fn the_rest_of_the_arguments_is_a_list_and_lists_can_be_spread() {
+    let run = run("
+        proces tel(eerste, ...rest) {
+            retour eerste + lengte(rest);
+        }
+        roep tel(1);
+        roep tel(...[10, 20, 30]);
+        roep tel(1, ...[2, 3], 4);
+    ");
+    assert_eq!(run.code, 0, "{}", run.stderr);
+    assert_eq!(run.lines(), ["1", "12", "4"]);
+}
+
+#[test]
+fn a_wrong_argument_is_an_error_at_the_call() {
+    let run = run("
+        proces groet(naam) {
+            retour naam;
+        }
+        roep \"begin\";
+        roep groet(wie: \"Jan\");
+    ");
+    assert_ne!(run.code, 0);
+    assert_eq!(run.lines(), ["begin"]);
+    assert!(run.stderr.contains("file.lox:6:"), "{}", run.stderr);
+    assert!(run.stderr.contains("groet"), "{}", run.stderr);
+}
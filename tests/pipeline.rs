@@ -0,0 +1,26 @@
+mod common;
+
+use common::run;
+
+#[test]
+fn a_pipeline_passes_the_value_as_the_first_argument() {
+    let run = run("
+        [5, 2, 9, 1] |> filter((x) => x > 1) |> sorteer() |> toon();
+        proces min_tien(x) {
+            retour x - 10;
+        }
+        roep 30 |> min_tien;
+    ");
+    assert_eq!(run.code, 0, "{}", run.stderr);
+    assert_eq!(run.lines(), ["[2, 5, 9]", "20"]);
+}
+
+#[test]
+fn a_pipeline_binds_looser_than_other_operators() {
+    let run = run("
+        laat y = 1 + 2 |> toon() |> (x) => x * 10;
+        roep y;
+    ");
+    assert_eq!(run.code, 0, "{}", run.stderr);
+    assert_eq!(run.lines(), ["3", "30"]);
+}
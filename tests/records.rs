@@ -0,0 +1,73 @@
+mod common;
+
+use common::run;
+
+#[test]
+fn tuples_are_indexed_and_destructured() {
+    let run = run("
+        laat paar = (1, \"een\");
+        roep paar[1];
+        laat (getal, tekst) = paar;
+        laat [a, b] = paar;
+        roep getal + a;
+        roep paar;
+    ");
+    assert_eq!(run.code, 0, "{}", run.stderr);
+    assert_eq!(run.lines(), ["een", "2", "(1, een)"]);
+}
+
+#[test]
+fn records_have_named_fields() {
+    let run = run("
+        record Punt(x, y);
+        laat p = Punt(3, 5);
+        roep p.x + p.y;
+        laat {x, y} = p;
+        roep x * y;
+        roep p;
+    ");
+    assert_eq!(run.code, 0, "{}", run.stderr);
+    assert_eq!(run.lines(), ["8", "15", "Punt(x: 3, y: 5)"]);
+}
+
+#[test]
+fn tuples_and_records_are_equal_by_their_contents() {
+    let run = run("
+        record Punt(x, y);
+        laat p = Punt(3, 5);
+        roep (p, \"hoek\") == (Punt(3, 5), \"hoek\");
+        roep p == Punt(5, 3);
+    ");
+    assert_eq!(run.code, 0, "{}", run.stderr);
+    assert_eq!(run.lines(), ["wellus", "nietus"]);
+}
+
+#[test]
+fn a_tuple_can_not_be_changed() {
+    let run = run("
+        laat paar = (1, 2);
+        roep \"begin\";
+        paar[0] = 3;
+    ");
+    assert_ne!(run.code, 0);
+    assert_eq!(run.lines(), ["begin"]);
+}
+
+#[test]
+fn a_tuple_can_be_empty_or_hold_one_value() {
+    let run = run("
+        laat leeg = ();
+        laat een = (1,);
+        roep leeg;
+        roep een;
+        roep (1, 2,);
+        roep een == (1,);
+        roep (1) == 1;
+        voor x in een roep x;
+    ");
+    assert_eq!(run.code, 0, "{}", run.stderr);
+    assert_eq!(
+        run.lines(),
+        ["()", "(1,)", "(1, 2)", "wellus", "wellus", "1"]
+    );
+}
@@ -0,0 +1,63 @@
+mod common;
+
+use common::run;
+
+#[test]
+fn the_default_limit_stops_deep_recursion() {
+    let run = run("
+        proces diep(n) {
+            als n == 0 retour 0;
+            retour 1 + diep(n - 1);
+        }
+        roep recursie_limiet();
+        roep probeer(() => diep(5000));
+    ");
+    assert_eq!(run.code, 0, "{}", run.stderr);
+    assert_eq!(run.lines()[0], "1000");
+    assert!(run.lines()[1].starts_with("Fout("), "{}", run.stdout);
+    assert!(run.lines()[1].contains("1000"), "{}", run.stdout);
+}
+
+#[test]
+fn tail_calls_do_not_count() {
+    let run = run("
+        proces tel(n, totaal = 0) {
+            als n == 0 retour totaal;
+            retour tel(n - 1, totaal + 1);
+        }
+        roep tel(100000);
+    ");
+    assert_eq!(run.code, 0, "{}", run.stderr);
+    assert_eq!(run.lines(), ["100000"]);
+}
+
+#[test]
+fn a_raised_limit_allows_deeper_recursion() {
+    let run = run("
+        recursie_limiet(3000);
+        proces diep(n) {
+            als n == 0 retour 0;
+            retour 1 + diep(n - 1);
+        }
+        roep diep(2500);
+    ");
+    assert_eq!(run.code, 0, "{}", run.stderr);
+    assert_eq!(run.lines(), ["2500"]);
+}
+
+#[test]
+fn a_raised_limit_still_stops_before_the_stack_overflows() {
+    let run = run("
+        roep recursie_limiet(100000000);
+        proces diep(n) {
+            als n == 0 retour 0;
+            retour 1 + diep(n - 1);
+        }
+        roep probeer(() => diep(10000000));
+        roep diep(10000000);
+    ");
+    assert_eq!(run.code, 70, "{}", run.stderr);
+    assert_eq!(run.codes(), ["R0113"]);
+    assert_eq!(run.lines()[0], "1000");
+    assert!(run.lines()[1].starts_with("Fout("), "{}", run.stdout);
+}
@@ -0,0 +1,107 @@
+mod common;
+
+use common::run;
+
+#[test]
+fn locals_are_read_from_their_slots() {
+    let run = run("
+        laat a = 1;
+        {
+            laat b = 2;
+            laat a = 3;
+            {
+                laat c = a + b;
+                roep c;
+            }
+            roep a;
+        }
+        roep a;
+    ");
+    assert_eq!(run.code, 0, "{}", run.stderr);
+    assert_eq!(run.lines(), ["5", "3", "1"]);
+}
+
+#[test]
+fn closures_keep_their_own_scopes() {
+    let run = run("
+        proces teller() {
+            laat n = 0;
+            retour () => n = n + 1;
+        }
+        laat a = teller();
+        laat b = teller();
+        a(); a();
+        roep a();
+        roep b();
+        laat fs = [];
+        voor i in [1, 2, 3] { voeg_toe(fs, () => i); }
+        roep [f() voor f in fs];
+    ");
+    assert_eq!(run.code, 0, "{}", run.stderr);
+    assert_eq!(run.lines(), ["3", "1", "[1, 2, 3]"]);
+}
+
+#[test]
+fn a_proces_can_use_a_global_declared_after_it() {
+    let run = run("
+        proces f() { retour later * 2; }
+        laat later = 21;
+        roep f();
+    ");
+    assert_eq!(run.code, 0, "{}", run.stderr);
+    assert_eq!(run.lines(), ["42"]);
+}
+
+#[test]
+fn unknown_names_are_found_before_running() {
+    let run = run("
+        roep 1;
+        proces f() { retour onbekend; }
+    ");
+    assert_eq!(run.code, 65);
+    assert_eq!(run.codes(), ["R0102"]);
+    assert!(run.stdout.is_empty());
+}
+
+#[test]
+fn code_outside_a_proces_can_not_use_a_global_before_it_is_declared() {
+    let run = run("
+        roep 1;
+        { roep x; }
+        laat x = 2;
+    ");
+    assert_eq!(run.code, 65);
+    assert_eq!(run.codes(), ["R0102"]);
+    assert!(run.stderr.contains("only declared here"), "{}", run.stderr);
+    assert!(run.stdout.is_empty());
+}
+
+#[test]
+fn reading_a_local_in_its_own_initializer_is_an_error() {
+    let run = run("
+        laat x = 1;
+        {
+            laat x = x + 1;
+        }
+    ");
+    assert_eq!(run.code, 65);
+    assert_eq!(run.codes(), ["R0114"]);
+}
+
+#[test]
+fn declaring_a_global_again_reuses_its_slot() {
+    let run = run("
+        laat x = 1;
+        proces lees() {
+            retour x;
+        }
+        laat x = x + 1;
+        terwijl x < 5 {
+            x = x + 1;
+        }
+        roep x;
+        roep lees();
+    ");
+    assert_eq!(run.code, 0, "{}", run.stderr);
+    assert_eq!(run.lines(), ["5", "5"]);
+}
@@ -0,0 +1,35 @@
+mod common;
+
+use common::run;
+
+#[test]
+fn an_unknown_name_suggests_a_close_one() {
+    let run = run("
+        laat breedte = 3;
+        roep bredte;
+    ");
+    assert_eq!(run.code, 65);
+    assert!(run.stderr.contains("'breedte'"), "{}", run.stderr);
+}
+
+#[test]
+fn a_name_that_is_nothing_like_it_gets_no_suggestion() {
+    let run = run("
+        laat breedte = 3;
+        roep breedte + xyz;
+    ");
+    assert_eq!(run.code, 65);
+    assert!(!run.stderr.contains("'breedte'"), "{}", run.stderr);
+}
+
+#[test]
+fn a_misspelled_keyword_suggests_the_keyword() {
+    let run = run("
+        laat i = 0;
+        terwil i < 3 {
+            i = i + 1;
+        }
+    ");
+    assert_eq!(run.code, 65);
+    assert!(run.stderr.contains("'terwijl'"), "{}", run.stderr);
+}
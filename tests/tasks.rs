@@ -0,0 +1,111 @@
+mod common;
+
+use common::run;
+
+#[test]
+fn tasks_take_turns_in_the_same_order() {
+    let run = run("
+        virtuele_klok();
+        proces schrijf(naam, n) {
+            voor i van 0 tot n {
+                roep naam + i;
+                lever slaap(10);
+            }
+            retour naam;
+        }
+        laat a = taak(schrijf, \"a\", 2);
+        laat b = taak(schrijf, \"b\", 2);
+        roep wacht([a, b]);
+        roep nu();
+    ");
+    assert_eq!(run.code, 0, "{}", run.stderr);
+    assert_eq!(run.lines(), ["a0", "b0", "a1", "b1", "[a, b]", "20"]);
+}
+
+#[test]
+fn a_channel_hands_values_from_one_task_to_another() {
+    let run = run("
+        virtuele_klok();
+        laat k = kanaal();
+        proces pinger(aantal) {
+            voor i van 0 tot aantal {
+                lever slaap(100);
+                verstuur(k, \"ping \" + i + \" om \" + nu());
+            }
+        }
+        proces lezer(aantal) {
+            voor i van 0 tot aantal {
+                laat bericht = lever ontvang(k);
+                roep bericht;
+            }
+        }
+        taak(pinger, 2);
+        wacht(taak(lezer, 2));
+    ");
+    assert_eq!(run.code, 0, "{}", run.stderr);
+    assert_eq!(run.lines(), ["ping 0 om 100", "ping 1 om 200"]);
+}
+
+#[test]
+fn every_wacht_on_a_failed_task_gives_its_error() {
+    let run = run("
+        virtuele_klok();
+        proces faal() {
+            lever slaap(10);
+            retour 1 / niks;
+        }
+        laat t = taak(faal);
+        roep probeer(() => wacht(t));
+        roep probeer(() => wacht(t));
+        wacht(t);
+    ");
+    assert_eq!(run.code, 70);
+    assert_eq!(run.codes(), ["R0209"]);
+    let fout = "Fout(/ can only be used on numbers.)";
+    assert_eq!(run.lines(), [fout, fout]);
+}
+
+#[test]
+fn a_task_waiting_for_a_failed_task_fails_too() {
+    let run = run("
+        virtuele_klok();
+        proces faal() {
+            lever slaap(10);
+            retour 1 / niks;
+        }
+        proces wachter(t) {
+            laat x = lever t;
+            roep \"hier komt hij niet\";
+            retour x;
+        }
+        laat t = taak(faal);
+        laat w = taak(wachter, t);
+        roep probeer(() => wacht(t));
+        roep probeer(() => wacht(w));
+    ");
+    assert_eq!(run.code, 0, "{}", run.stderr);
+    let fout = "Fout(/ can only be used on numbers.)";
+    assert_eq!(run.lines(), [fout, fout]);
+}
+
+#[test]
+fn a_failed_task_does_not_stop_a_wacht_on_another_task() {
+    let run = run("
+        virtuele_klok();
+        proces faal() {
+            retour 1 / niks;
+        }
+        proces werk() {
+            lever slaap(10);
+            retour \"klaar\";
+        }
+        laat t = taak(faal);
+        roep wacht(taak(werk));
+        roep probeer(() => wacht(t));
+    ");
+    assert_eq!(run.code, 0, "{}", run.stderr);
+    assert_eq!(
+        run.lines(),
+        ["klaar", "Fout(/ can only be used on numbers.)"]
+    );
+}
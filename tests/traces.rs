@@ -0,0 +1,81 @@
+mod common;
+
+use common::run;
+
+#[test]
+fn an_error_shows_the_steps_that_led_to_it() {
+    let run = run("proces deel(x) {
+  retour 1 / x;
+}
+laat i = 0;
+terwijl i < 3 {
+  i = i + 1;
+  als i == 2 deel(niks);
+}
+");
+    assert_eq!(run.code, 70);
+    let lus = run.stderr.find(" 2 (file.lox:5)\n");
+    let aanroep = run.stderr.find(" deel (file.lox:7)\n");
+    assert!(lus.is_some() && aanroep.is_some(), "{}", run.stderr);
+    assert!(lus < aanroep, "{}", run.stderr);
+}
+
+#[test]
+fn repeated_recursion_is_collapsed() {
+    let run = run("proces f(n) {
+  als n == 0 retour 1 / niks;
+  retour 1 + f(n - 1);
+}
+f(50);
+");
+    assert_eq!(run.code, 70);
+    assert_eq!(
+        run.stderr.matches(" f (file.lox:3)").count(),
+        1,
+        "{}",
+        run.stderr
+    );
+    assert!(run.stderr.contains(" 49 "), "{}", run.stderr);
+}
+
+#[test]
+fn laatste_spoor_gives_the_steps_of_the_caught_error() {
+    let run = run("proces deel(x) {
+  retour 1 / x;
+}
+roep is_fout(probeer(() => deel(niks)));
+roep laatste_spoor();
+");
+    assert_eq!(run.code, 0, "{}", run.stderr);
+    let lines = run.lines();
+    assert_eq!(lines[0], "wellus");
+    assert!(lines[1].ends_with(" deel, file.lox, 4)]"), "{}", run.stdout);
+}
+
+#[test]
+fn processen_called_by_natives_are_called_from_the_native_call() {
+    let run = run("
+        proces cmp(a, b) { retour a < niks; }
+        roep probeer(() => sorteer([2, 1], cmp));
+        roep laatste_spoor();
+    ");
+    assert_eq!(run.code, 0, "{}", run.stderr);
+    let lines = run.lines();
+    assert!(lines[1].contains("(function cmp, "), "{}", run.stdout);
+    assert!(lines[1].ends_with(".lox, 3)]"), "{}", run.stdout);
+}
+
+#[test]
+fn tasks_are_run_from_the_wacht_that_waits_for_them() {
+    let run = run("
+        proces lus() {
+            lever slaap(1);
+            retour 1 / niks;
+        }
+        virtuele_klok();
+        wacht(taak(lus));
+    ");
+    assert_eq!(run.code, 70);
+    assert!(run.stderr.contains(".lox:7)\n"), "{}", run.stderr);
+    assert!(!run.stderr.contains("<input>"), "{}", run.stderr);
+}
@@ -0,0 +1,77 @@
+mod common;
+
+use common::{run, run_with};
+
+const UNUSED: &str = "
+    proces f() {
+        laat ongebruikt = 1;
+        retour 2;
+    }
+    roep f();
+";
+
+#[test]
+fn warnings_do_not_stop_the_program() {
+    let run = run(UNUSED);
+    assert_eq!(run.code, 0);
+    assert_eq!(run.codes(), ["R0501"]);
+    assert_eq!(run.lines(), ["2"]);
+}
+
+#[test]
+fn sta_toe_hides_a_warning() {
+    let run = run(&format!("// rox: sta_toe(R0501)\n{}", UNUSED));
+    assert_eq!(run.code, 0);
+    assert!(run.codes().is_empty(), "{}", run.stderr);
+}
+
+#[test]
+fn verbied_makes_a_warning_an_error() {
+    let run = run(&format!("// rox: verbied(R0501)\n{}", UNUSED));
+    assert_eq!(run.code, 65);
+    assert_eq!(run.codes(), ["R0501"]);
+    assert!(run.stdout.is_empty());
+}
+
+#[test]
+fn deny_warnings_makes_every_warning_an_error() {
+    let run = run_with(UNUSED, &["--deny-warnings"]);
+    assert_eq!(run.code, 65);
+    assert_eq!(run.codes(), ["R0501"]);
+}
+
+#[test]
+fn a_pragma_wins_over_deny_warnings() {
+    let source = format!("// rox: sta_toe(R0501)\n{}", UNUSED);
+    let run = run_with(&source, &["--deny-warnings"]);
+    assert_eq!(run.code, 0, "{}", run.stderr);
+}
+
+#[test]
+fn unknown_pragmas_are_errors() {
+    let run = run("// rox: sta_toe(R0102)\nroep 1;");
+    assert_eq!(run.code, 65);
+    assert_eq!(run.codes(), ["R0055"]);
+}
+
+#[test]
+fn likely_mistakes_are_warned_about() {
+    let run = run("
+        proces f() {
+            retour 1;
+            roep 2;
+        }
+        roep f();
+        roep 1 == \"1\";
+        terwijl nietus {}
+        voor i van 0 tot 1 {
+            laat i = 5;
+            roep i;
+        }
+    ");
+    assert_eq!(run.code, 0, "{}", run.stderr);
+    for code in ["R0502", "R0503", "R0504", "R0505"] {
+        assert!(run.codes().contains(&code), "{}", run.stderr);
+    }
+    assert_eq!(run.lines(), ["1", "nietus", "5"]);
+}